@@ -0,0 +1,144 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Generates a test per example and part from the expected answers in `src/examples/<year>/NN.toml`.
+//! Parts without an expected answer, also of example files without a section, get an ignored test,
+//! so unsolved parts show up in the test summary.
+use std::{collections::BTreeMap, env, fmt::Write, fs, path::Path};
+
+const PARTS: [(u8, &str); 2] = [(1, "part_one"), (2, "part_two")];
+
+/// The manifest parser of the crate, shared so the generated tests match what the runner reads.
+#[allow(dead_code)]
+mod manifest {
+    include!("src/manifest.rs");
+}
+
+/// The parts with an expected answer of every example of a day, `None` for the default example.
+type Examples = BTreeMap<Option<String>, Vec<u8>>;
+
+fn ident(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Reads the manifests `<year>/NN.toml` in `examples` and adds the example files `<year>/NN_<name>.txt`
+/// they have no section for, ordered by year and day.
+fn days(examples: &Path) -> BTreeMap<(i16, u8), Examples> {
+    let mut days: BTreeMap<(i16, u8), Examples> = BTreeMap::new();
+
+    for year_dir in fs::read_dir(examples).expect("could not read src/examples") {
+        let year_dir = year_dir.expect("could not read src/examples").path();
+        let year = match year_dir
+            .file_name()
+            .and_then(|name| name.to_str()?.parse().ok())
+        {
+            Some(year) if year_dir.is_dir() => year,
+            _ => continue,
+        };
+
+        let entries = fs::read_dir(&year_dir)
+            .unwrap_or_else(|e| panic!("could not read {}: {}", year_dir.display(), e));
+        for path in entries.filter_map(|entry| Some(entry.ok()?.path())) {
+            let file_name = match path.file_name().and_then(|name| name.to_str()) {
+                Some(file_name) => file_name,
+                None => continue,
+            };
+
+            if let Some(day) = file_name.strip_suffix(".toml").and_then(parse_day) {
+                let contents = fs::read_to_string(&path)
+                    .unwrap_or_else(|e| panic!("could not read {}: {}", path.display(), e));
+                // fail the build rather than silently dropping the day's tests.
+                let sections = manifest::parse(&contents)
+                    .unwrap_or_else(|e| panic!("could not parse {}: {}", path.display(), e));
+
+                let examples = days.entry((year, day)).or_default();
+                for section in sections {
+                    examples
+                        .entry(section.name.map(str::to_owned))
+                        .or_default()
+                        .extend(section.entries.iter().map(|entry| entry.part));
+                }
+            } else if let Some(stem) = file_name.strip_suffix(".txt") {
+                let (day, name) = match stem.split_once('_') {
+                    Some((_, "")) => continue,
+                    Some((day, name)) => (day, Some(name.to_owned())),
+                    None => (stem, None),
+                };
+                if let Some(day) = parse_day(day) {
+                    days.entry((year, day))
+                        .or_default()
+                        .entry(name)
+                        .or_default();
+                }
+            }
+        }
+    }
+
+    days
+}
+
+/// Parses the zero-padded day of an example file name, e.g. `08`.
+fn parse_day(s: &str) -> Option<u8> {
+    let day = s.parse().ok()?;
+    (format!("{:02}", day) == s).then_some(day)
+}
+
+fn main() {
+    let examples_dir = Path::new("src").join("examples");
+    println!("cargo:rerun-if-changed={}", examples_dir.display());
+
+    let mut out = String::new();
+    let mut current_year = None;
+
+    for ((year, day), examples) in days(&examples_dir) {
+        if current_year != Some(year) {
+            if current_year.is_some() {
+                writeln!(out, "}}").unwrap();
+            }
+            writeln!(out, "mod y{} {{", year).unwrap();
+            current_year = Some(year);
+        }
+
+        writeln!(out, "    mod day{:02} {{", day).unwrap();
+
+        for (section, parts) in examples {
+            let (name, prefix) = match section.as_deref() {
+                None => ("None".to_owned(), "example".to_owned()),
+                Some(name) => (
+                    format!("Some({:?})", name),
+                    format!("example_{}", ident(name)),
+                ),
+            };
+
+            for (part, key) in PARTS {
+                if !parts.contains(&part) {
+                    writeln!(out, "        #[ignore = \"no expected answer\"]").unwrap();
+                }
+                writeln!(
+                    out,
+                    "        #[test]\n        fn {}_{}() {{\n            crate::examples::assert_example({}, {}, {}, {});\n        }}",
+                    prefix, key, year, day, name, part
+                )
+                .unwrap();
+            }
+        }
+
+        writeln!(out, "    }}").unwrap();
+    }
+
+    if current_year.is_some() {
+        writeln!(out, "}}").unwrap();
+    }
+
+    let dest = Path::new(&env::var("OUT_DIR").unwrap()).join("example_tests.rs");
+    fs::write(dest, out).expect("could not write generated example tests");
+}
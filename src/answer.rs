@@ -0,0 +1,194 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    fmt::{self, Display},
+    time::Duration,
+};
+
+/// The answer to a single puzzle part.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(u64),
+    SignedInteger(i64),
+    Text(String),
+    /// Multi-line ASCII art, e.g. the letters drawn on a screen. Rows are separated by `\n`.
+    AsciiArt(String),
+}
+
+impl Answer {
+    /// Builds an [`Answer::AsciiArt`] from the rows of a drawing.
+    pub fn ascii_art<T: AsRef<str>>(rows: impl IntoIterator<Item = T>) -> Self {
+        let rows: Vec<String> = rows
+            .into_iter()
+            .map(|row| row.as_ref().to_owned())
+            .collect();
+        Answer::AsciiArt(rows.join("\n"))
+    }
+
+    /// Whether the answer spans more than one line when printed.
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::AsciiArt(_))
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::SignedInteger(value) => write!(f, "{}", value),
+            Answer::Text(value) | Answer::AsciiArt(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($variant:ident, $target:ty, $($source:ty),*) => {
+        $(
+            impl From<$source> for Answer {
+                fn from(value: $source) -> Self {
+                    Answer::$variant(value as $target)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(Integer, u64, u8, u16, u32, u64, usize);
+impl_from_integer!(SignedInteger, i64, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+/// The result of running a single puzzle part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveOutcome {
+    /// The part returned `None`.
+    NotImplemented,
+    Solved(Answer),
+    /// The part returned an error, stored as its message.
+    Failed(String),
+    /// The part panicked, stored as the panic message.
+    Panicked(String),
+    /// The part did not finish within the given time limit.
+    TimedOut(Duration),
+}
+
+impl SolveOutcome {
+    pub fn answer(&self) -> Option<&Answer> {
+        match self {
+            SolveOutcome::Solved(answer) => Some(answer),
+            _ => None,
+        }
+    }
+
+    /// Describes why the part did not produce an answer, `None` if it was solved or is not implemented.
+    pub fn error(&self) -> Option<String> {
+        match self {
+            SolveOutcome::Failed(error) | SolveOutcome::Panicked(error) => Some(error.clone()),
+            SolveOutcome::TimedOut(_) => Some(self.to_string()),
+            _ => None,
+        }
+    }
+}
+
+impl Display for SolveOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveOutcome::NotImplemented => write!(f, "not solved."),
+            SolveOutcome::Solved(answer) => write!(f, "{}", answer),
+            SolveOutcome::Failed(error) => write!(f, "failed: {}", error),
+            SolveOutcome::Panicked(message) => write!(f, "panicked: {}", message),
+            SolveOutcome::TimedOut(limit) => {
+                write!(f, "timed out after {}s", limit.as_secs_f64())
+            }
+        }
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for SolveOutcome {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(answer) => SolveOutcome::Solved(answer.into()),
+            None => SolveOutcome::NotImplemented,
+        }
+    }
+}
+
+impl<T: Into<Answer>, E: Display> From<Result<T, E>> for SolveOutcome {
+    fn from(value: Result<T, E>) -> Self {
+        match value {
+            Ok(answer) => SolveOutcome::Solved(answer.into()),
+            Err(error) => SolveOutcome::Failed(error.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_option() {
+        assert_eq!(
+            SolveOutcome::from(Some(24000_u32)),
+            SolveOutcome::Solved(Answer::Integer(24000))
+        );
+        assert_eq!(
+            SolveOutcome::from(Some(-3_i32)),
+            SolveOutcome::Solved(Answer::SignedInteger(-3))
+        );
+        assert_eq!(
+            SolveOutcome::from(Some(String::from("CMZ"))),
+            SolveOutcome::Solved(Answer::Text("CMZ".into()))
+        );
+        assert_eq!(
+            SolveOutcome::from(None::<u32>),
+            SolveOutcome::NotImplemented
+        );
+    }
+
+    #[test]
+    fn test_from_result() {
+        assert_eq!(
+            SolveOutcome::from(Err::<u32, _>("bad input")),
+            SolveOutcome::Failed("bad input".into())
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(SolveOutcome::NotImplemented.to_string(), "not solved.");
+        assert_eq!(
+            SolveOutcome::Panicked("index out of bounds".into()).to_string(),
+            "panicked: index out of bounds"
+        );
+        assert_eq!(
+            SolveOutcome::TimedOut(Duration::from_millis(2500)).to_string(),
+            "timed out after 2.5s"
+        );
+        assert_eq!(
+            SolveOutcome::TimedOut(Duration::from_secs(10)).error(),
+            Some("timed out after 10s".into())
+        );
+        assert_eq!(SolveOutcome::NotImplemented.error(), None);
+    }
+
+    #[test]
+    fn test_ascii_art() {
+        let answer = Answer::ascii_art(["#..", ".#."]);
+        assert_eq!(answer, Answer::AsciiArt("#..\n.#.".into()));
+        assert!(answer.is_multiline());
+        assert_eq!(answer.to_string(), "#..\n.#.");
+    }
+}
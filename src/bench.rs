@@ -0,0 +1,278 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    io::{self, Write},
+    time::Duration,
+};
+
+use crate::{solve_part, Solution, SolveOutcome, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// How often and how long a single part is benchmarked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Untimed runs before sampling starts.
+    pub warmup: u32,
+    /// Maximum number of timed runs.
+    pub iterations: u32,
+    /// Stop sampling once the timed runs took this long in total. At least one sample is always taken.
+    pub budget: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmup: 3,
+            iterations: 100,
+            budget: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Parses the time budget of a part in (fractional) seconds, e.g. `2.5`.
+pub fn parse_budget(s: &str) -> Result<Duration, String> {
+    match s.parse::<f64>().map(Duration::try_from_secs_f64) {
+        Ok(Ok(budget)) if !budget.is_zero() => Ok(budget),
+        _ => Err(format!(
+            "invalid budget \"{}\", expected a positive number of seconds",
+            s
+        )),
+    }
+}
+
+/// Summary statistics over the samples of a benchmarked part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// Sample standard deviation, zero for fewer than two samples.
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Computes statistics over `samples`. Returns `None` if there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0_f64
+        };
+
+        Some(Stats {
+            samples: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        })
+    }
+}
+
+/// The benchmark result of a single day and part.
+#[derive(Debug, Clone)]
+pub struct BenchReport {
+    pub year: i16,
+    pub day: u8,
+    pub part: u8,
+    /// Outcome of the first run. Parts that are not solved are not sampled.
+    pub outcome: SolveOutcome,
+    pub stats: Option<Stats>,
+}
+
+/// Benchmarks `part` of `solution` against `input` using the same timing path as [`solve!`](crate::solve).
+pub fn bench_part(
+    solution: &dyn Solution,
+    part: u8,
+    input: &str,
+    config: &BenchConfig,
+) -> BenchReport {
+    let outcome = solve_part(solution, part, input).value;
+
+    let stats = match outcome {
+        SolveOutcome::Solved(_) => {
+            for _ in 0..config.warmup {
+                solve_part(solution, part, input);
+            }
+
+            let mut samples = Vec::new();
+            let mut spent = Duration::ZERO;
+
+            while samples.len() < config.iterations.max(1) as usize
+                && (samples.is_empty() || spent < config.budget)
+            {
+                let elapsed = solve_part(solution, part, input).elapsed;
+                spent += elapsed;
+                samples.push(elapsed);
+            }
+
+            Stats::from_samples(&samples)
+        }
+        _ => None,
+    };
+
+    BenchReport {
+        year: solution.year(),
+        day: solution.day(),
+        part,
+        outcome,
+        stats,
+    }
+}
+
+/// Writes a human readable line with the statistics of `report`.
+pub fn write_text(out: &mut impl Write, report: &BenchReport) -> io::Result<()> {
+    write!(
+        out,
+        "{}Day {:02} part {}:{} ",
+        ANSI_BOLD, report.day, report.part, ANSI_RESET
+    )?;
+
+    match report.stats {
+        Some(stats) => writeln!(
+            out,
+            "min {:.2?} · median {:.2?} · mean {:.2?} · stddev {:.2?} {}({} samples){}",
+            stats.min,
+            stats.median,
+            stats.mean,
+            stats.stddev,
+            ANSI_ITALIC,
+            stats.samples,
+            ANSI_RESET
+        ),
+        None => writeln!(out, "{}", report.outcome),
+    }
+}
+
+/// Writes `reports` as CSV with a header row. Durations are in nanoseconds.
+pub fn write_csv(out: &mut impl Write, reports: &[BenchReport]) -> io::Result<()> {
+    writeln!(
+        out,
+        "year,day,part,samples,min_ns,median_ns,mean_ns,stddev_ns"
+    )?;
+
+    for report in reports {
+        match report.stats {
+            Some(stats) => writeln!(
+                out,
+                "{},{},{},{},{},{},{},{}",
+                report.year,
+                report.day,
+                report.part,
+                stats.samples,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.stddev.as_nanos()
+            )?,
+            None => writeln!(out, "{},{},{},0,,,,", report.year, report.day, report.part)?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes `reports` as a single JSON object. Durations are in nanoseconds.
+pub fn write_json(out: &mut impl Write, reports: &[BenchReport]) -> io::Result<()> {
+    writeln!(out, "{{")?;
+    writeln!(out, "  \"benchmarks\": [")?;
+
+    for (i, report) in reports.iter().enumerate() {
+        let separator = if i + 1 < reports.len() { "," } else { "" };
+
+        match report.stats {
+            Some(stats) => writeln!(
+                out,
+                "    {{\"year\": {}, \"day\": {}, \"part\": {}, \"samples\": {}, \"min_ns\": {}, \"median_ns\": {}, \"mean_ns\": {}, \"stddev_ns\": {}}}{}",
+                report.year,
+                report.day,
+                report.part,
+                stats.samples,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.stddev.as_nanos(),
+                separator
+            )?,
+            None => writeln!(
+                out,
+                "    {{\"year\": {}, \"day\": {}, \"part\": {}, \"samples\": 0}}{}",
+                report.year, report.day, report.part, separator
+            )?,
+        }
+    }
+
+    writeln!(out, "  ]")?;
+    writeln!(out, "}}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::y2022::day01::Day01;
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<Duration> = [8, 2, 6, 4].into_iter().map(Duration::from_nanos).collect();
+        let stats = Stats::from_samples(&samples).unwrap();
+
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.min, Duration::from_nanos(2));
+        assert_eq!(stats.median, Duration::from_nanos(5));
+        assert_eq!(stats.mean, Duration::from_nanos(5));
+        assert_eq!(stats.stddev, Duration::from_nanos(3));
+
+        let single = Stats::from_samples(&[Duration::from_nanos(7)]).unwrap();
+        assert_eq!(single.median, Duration::from_nanos(7));
+        assert_eq!(single.stddev, Duration::ZERO);
+
+        assert!(Stats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn test_parse_budget() {
+        assert_eq!(parse_budget("2.5"), Ok(Duration::from_millis(2500)));
+        for invalid in ["0", "-1", "NaN", "inf", "1e30", "soon"] {
+            assert!(parse_budget(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_bench_part() {
+        let input = crate::read_file("examples", 2022, 1).unwrap();
+        let config = BenchConfig {
+            warmup: 1,
+            iterations: 5,
+            budget: Duration::from_secs(1),
+        };
+
+        let report = bench_part(&Day01, 1, &input, &config);
+        assert_eq!(report.outcome, SolveOutcome::Solved(24000_u32.into()));
+        assert_eq!(report.stats.map(|stats| stats.samples), Some(5));
+
+        let budget = BenchConfig {
+            budget: Duration::ZERO,
+            ..config
+        };
+        let report = bench_part(&Day01, 2, &input, &budget);
+        assert_eq!(report.stats.map(|stats| stats.samples), Some(1));
+    }
+}
@@ -2,104 +2,113 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use std::io::Write;
-use std::path::PathBuf;
-use std::{env::temp_dir, io, process::Command};
 use std::{fs, process};
 
+use advent_of_code::{
+    client::{self, Client},
+    finish_args, puzzle,
+    scaffold::parse_day,
+    year_path, DEFAULT_YEAR,
+};
+
 struct Args {
     day: u8,
-    year: Option<i16>,
+    year: i16,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    Ok(Args {
-        day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
-    })
-}
-
-fn remove_file(path: &PathBuf) {
-    #[allow(unused_must_use)]
-    {
-        fs::remove_file(path);
-    }
-}
+    let parsed = Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        day: args.free_from_fn(parse_day)?,
+    };
 
-fn exit_with_status(status: i32, path: &PathBuf) -> ! {
-    remove_file(path);
-    process::exit(status);
+    finish_args(args)?;
+    Ok(parsed)
 }
 
 fn main() {
-    // acquire a temp file path to write aoc-cli output to.
-    // aoc-cli expects this file not to be present - delete just in case.
-    let mut tmp_file_path = temp_dir();
-    tmp_file_path.push("aoc_input_tmp");
-    remove_file(&tmp_file_path);
-
     let args = match parse_args() {
         Ok(args) => args,
         Err(e) => {
             eprintln!("Failed to process arguments: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("Usage: `cargo download <day> [--year <year>]`");
+            process::exit(1);
         }
     };
 
-    let day_padded = format!("{:02}", args.day);
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-
-    // check if aoc binary exists and is callable.
-    if Command::new("aoc").arg("-V").output().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        exit_with_status(1, &tmp_file_path);
-    }
-
-    let mut cmd_args = vec![];
-
-    if let Some(year) = args.year {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
-    }
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Failed to create client: {}", e);
+            process::exit(1);
+        }
+    };
 
-    cmd_args.append(&mut vec![
-        "--input-file".into(),
-        tmp_file_path.to_string_lossy().to_string(),
-        "--day".into(),
-        args.day.to_string(),
-        "download".into(),
-    ]);
+    let file = |folder: &str, extension: &str| {
+        year_path(folder, args.year).join(format!("{:02}.{}", args.day, extension))
+    };
+    let input_path = file("inputs", "txt");
+    let puzzle_path = file("puzzles", "md");
+    let example_path = file("examples", "txt");
 
-    println!("Downloading input with >aoc {}", cmd_args.join(" "));
+    println!(
+        "Downloading input of day {} ({}) from {}...",
+        args.day,
+        args.year,
+        client.base_url()
+    );
 
-    match Command::new("aoc").args(cmd_args).output() {
-        Ok(cmd_output) => {
-            io::stdout()
-                .write_all(&cmd_output.stdout)
-                .expect("could not write cmd stdout to pipe.");
-            io::stderr()
-                .write_all(&cmd_output.stderr)
-                .expect("could not write cmd stderr to pipe.");
-            if !cmd_output.status.success() {
-                exit_with_status(1, &tmp_file_path);
-            }
+    let input = match client.input(args.year, args.day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to download input: {}", e);
+            process::exit(1);
         }
+    };
+
+    match client::write_atomic(&input_path, &input) {
+        Ok(_) => println!("Wrote input to \"{}\"", input_path.display()),
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("Failed to write input file: {}", e);
+            process::exit(1);
         }
     }
 
-    match fs::copy(&tmp_file_path, &input_path) {
-        Ok(_) => {
-            println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            exit_with_status(0, &tmp_file_path);
+    let page = match client.puzzle(args.year, args.day) {
+        Ok(page) => page,
+        Err(e) => {
+            eprintln!("Failed to download puzzle: {}", e);
+            process::exit(1);
         }
+    };
+
+    match client::write_atomic(&puzzle_path, &puzzle::page_to_markdown(&page)) {
+        Ok(_) => println!("Wrote puzzle description to \"{}\"", puzzle_path.display()),
         Err(e) => {
-            eprintln!("could not copy downloaded input to input file: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("Failed to write puzzle file: {}", e);
+            process::exit(1);
         }
     }
+
+    // never replace an example that was already filled in by hand.
+    let has_example =
+        fs::read_to_string(&example_path).is_ok_and(|example| !example.trim().is_empty());
+
+    match puzzle::first_example(&page) {
+        Some(_) if has_example => println!("Kept existing example \"{}\"", example_path.display()),
+        Some(example) => match client::write_atomic(&example_path, &example) {
+            Ok(_) => println!("Wrote example to \"{}\"", example_path.display()),
+            Err(e) => {
+                eprintln!("Failed to write example file: {}", e);
+                process::exit(1);
+            }
+        },
+        None => println!("No example found in the puzzle description."),
+    }
+
+    println!("---");
+    println!("🎄 Successfully downloaded day {}.", args.day);
 }
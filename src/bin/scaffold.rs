@@ -3,112 +3,262 @@
  * There is no need to edit this file unless you want to change template functionality.
  */
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs,
+    io::{self, ErrorKind},
+    path::PathBuf,
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
-}
+use advent_of_code::{
+    finish_args,
+    scaffold::{self, Transaction},
+    src_path, template, year_path, DEFAULT_YEAR,
+};
+
+const ANSWER_TYPES: [&str; 4] = ["u32", "u64", "i64", "String"];
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+struct Args {
+    day: u8,
+    year: i16,
+    title: String,
+    answer: String,
+    parser: bool,
+    force: bool,
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+fn parse_answer(s: &str) -> Result<String, String> {
+    match ANSWER_TYPES.contains(&s) {
+        true => Ok(s.to_owned()),
+        false => Err(format!(
+            "unknown answer type \"{}\", expected one of: {}",
+            s,
+            ANSWER_TYPES.join(", ")
+        )),
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        title: args
+            .opt_value_from_str(["-t", "--title"])?
+            .unwrap_or_else(|| "Untitled".into()),
+        answer: args
+            .opt_value_from_fn(["-a", "--answer"], parse_answer)?
+            .unwrap_or_else(|| ANSWER_TYPES[0].into()),
+        parser: args.contains("--parser"),
+        force: args.contains("--force"),
+        day: args.free_from_fn(scaffold::parse_day)?,
+    };
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
-    }
+    finish_args(args)?;
+    Ok(parsed)
+}
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+fn render(name: &str, values: &[(&str, &str)]) -> String {
+    let rendered = template::load(name)
+        .map_err(|e| e.to_string())
+        .and_then(|contents| template::render(&contents, values));
+
+    match rendered {
+        Ok(rendered) => rendered,
+        Err(e) => {
+            eprintln!(
+                "Failed to render template \"{}\": {}",
+                template::template_path(name).display(),
+                e
+            );
+            process::exit(1);
+        }
     }
 }
-"###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
-    let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+/// A file scaffold creates. Files without contents are data files that are created empty and never overwritten.
+struct ScaffoldFile {
+    path: PathBuf,
+    contents: Option<String>,
+    kind: &'static str,
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+/// Adds the day to the registry of its year, or creates the registry from `year_module` and registers the year.
+fn register(args: &Args, year_module: &str, transaction: &mut Transaction) -> io::Result<()> {
+    let invalid = |e| io::Error::new(ErrorKind::InvalidData, e);
+    let year_registry_path = src_path("days")
+        .join(format!("y{}", args.year))
+        .join("mod.rs");
+
+    if year_registry_path.exists() {
+        let registry = fs::read_to_string(&year_registry_path)?;
+        match scaffold::register_day(&registry, args.day).map_err(invalid)? {
+            Some(registry) => {
+                transaction.write(&year_registry_path, &registry)?;
+                println!(
+                    "Registered day {} in \"{}\"",
+                    args.day,
+                    year_registry_path.display()
+                );
+            }
+            None => println!("Day {} is already registered", args.day),
+        }
+        return Ok(());
+    }
+
+    transaction.write(&year_registry_path, year_module)?;
+    println!(
+        "Created registry of {} \"{}\"",
+        args.year,
+        year_registry_path.display()
+    );
+
+    let registry_path = src_path("days").join("mod.rs");
+    let registry = fs::read_to_string(&registry_path)?;
+    if let Some(registry) = scaffold::register_year(&registry, args.year).map_err(invalid)? {
+        transaction.write(&registry_path, &registry)?;
+        println!(
+            "Registered year {} in \"{}\"",
+            args.year,
+            registry_path.display()
+        );
+    }
+
+    Ok(())
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn scaffold(
+    args: &Args,
+    files: &[ScaffoldFile],
+    year_module: &str,
+    transaction: &mut Transaction,
+) -> io::Result<()> {
+    for file in files {
+        match (&file.contents, file.path.exists()) {
+            (Some(contents), exists) => {
+                transaction.write(&file.path, contents)?;
+                let action = if exists { "Overwrote" } else { "Created" };
+                println!("{} {} file \"{}\"", action, file.kind, file.path.display());
+            }
+            (None, false) => {
+                transaction.write(&file.path, "")?;
+                println!(
+                    "Created empty {} file \"{}\"",
+                    file.kind,
+                    file.path.display()
+                );
+            }
+            (None, true) => {
+                println!(
+                    "Kept existing {} file \"{}\"",
+                    file.kind,
+                    file.path.display()
+                );
+            }
+        }
+    }
+
+    register(args, year_module, transaction)
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
-        Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            eprintln!(
+                "Usage: `cargo scaffold <day> [--title <title>] [--year <year>] [--answer {}] [--parser] [--force]`",
+                ANSWER_TYPES.join("|")
+            );
             process::exit(1);
         }
     };
 
-    let day_padded = format!("{:02}", day);
+    let day = args.day.to_string();
+    let day_padded = format!("{:02}", args.day);
+    let year = args.year.to_string();
+    let title_string = format!("{:?}", args.title);
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    let values = [
+        ("day", day.as_str()),
+        ("day_padded", day_padded.as_str()),
+        ("year", year.as_str()),
+        ("title", args.title.as_str()),
+        ("title_string", title_string.as_str()),
+        ("answer", args.answer.as_str()),
+    ];
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {}", e);
-            process::exit(1);
-        }
+    let day_template = if args.parser {
+        "day_parser.rs"
+    } else {
+        "day.rs"
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
-        Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
-            process::exit(1);
-        }
+    // render everything up front, so a broken template does not leave a half scaffolded day behind.
+    let module = render(day_template, &values);
+    let year_module = render("year.rs", &values);
+    let bin = render("bin.rs", &values);
+    let expected = render("examples.toml", &values);
+
+    let file = |folder: &str, name: String, contents: Option<String>, kind| ScaffoldFile {
+        path: year_path(folder, args.year).join(name),
+        contents,
+        kind,
+    };
+    let mut files = vec![
+        ScaffoldFile {
+            path: src_path("days")
+                .join(format!("y{}", args.year))
+                .join(format!("day{}.rs", day_padded)),
+            contents: Some(module),
+            kind: "module",
+        },
+        file(
+            "examples",
+            format!("{}.toml", day_padded),
+            Some(expected),
+            "expected answers",
+        ),
+        file("inputs", format!("{}.txt", day_padded), None, "input"),
+        file("examples", format!("{}.txt", day_padded), None, "example"),
+    ];
+    // binaries are only named by day, so they run the days of the default year.
+    if args.year == DEFAULT_YEAR {
+        files.push(ScaffoldFile {
+            path: src_path("bin").join(format!("{}.rs", day_padded)),
+            contents: Some(bin),
+            kind: "binary",
+        });
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
-            process::exit(1);
+    let existing: Vec<&ScaffoldFile> = files.iter().filter(|file| file.path.exists()).collect();
+    if !existing.is_empty() && !args.force {
+        eprintln!("Day {} is already scaffolded, these files exist:", args.day);
+        for file in existing {
+            eprintln!("  - \"{}\"", file.path.display());
         }
+        eprintln!("Pass `--force` to overwrite the module, binary and expected answers. Inputs and examples are always kept.");
+        process::exit(1);
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
-            process::exit(1);
+    let mut transaction = Transaction::default();
+
+    if let Err(e) = scaffold(&args, &files, &year_module, &mut transaction) {
+        eprintln!("Failed to scaffold day {}: {}", args.day, e);
+        match transaction.rollback() {
+            Ok(_) => eprintln!("Rolled back all changes."),
+            Err(e) => eprintln!("Failed to roll back changes: {}", e),
         }
+        process::exit(1);
     }
 
     println!("---");
+    let year_flag = match args.year {
+        DEFAULT_YEAR => String::new(),
+        year => format!(" --year {}", year),
+    };
     println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
+        "🎄 Type `cargo solve {}{}` to run your solution.",
+        &day_padded, year_flag
     );
 }
@@ -0,0 +1,97 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{fs, io, path::PathBuf, process, time::Duration};
+
+use advent_of_code::{
+    days, finish_args,
+    runner::{parse_part, parse_timeout},
+    scaffold::parse_day,
+    watch, InputSource, Solution, DEFAULT_YEAR,
+};
+
+struct Args {
+    day: u8,
+    year: i16,
+    input: Option<InputSource>,
+    check: bool,
+    part: Option<u8>,
+    report: Option<PathBuf>,
+    timeout: Option<Duration>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        input: args
+            .opt_value_from_str::<_, String>("--input")?
+            .as_deref()
+            .map(InputSource::from),
+        check: args.contains("--check"),
+        part: args.opt_value_from_fn(["-p", "--part"], parse_part)?,
+        report: args.opt_value_from_str("--report")?,
+        timeout: args.opt_value_from_fn("--timeout", parse_timeout)?,
+        day: args.free_from_fn(parse_day)?,
+    };
+
+    finish_args(args)?;
+    Ok(parsed)
+}
+
+/// Runs the examples and then the input, and writes the outcomes to `--report` for `cargo rerun`.
+fn check(solution: &'static dyn Solution, args: &Args) {
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let (answers, passed) = match watch::check(solution, &parts, args.timeout, &mut io::stdout()) {
+        Ok(result) => result,
+        Err(e) => {
+            eprintln!("Failed to run examples: {}", e);
+            process::exit(1);
+        }
+    };
+
+    if let Some(path) = &args.report {
+        if let Err(e) = fs::write(path, answers.to_string()) {
+            eprintln!("Failed to write report: {}", e);
+            process::exit(1);
+        }
+    }
+
+    if !passed {
+        process::exit(1);
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            eprintln!(
+                "Usage: `cargo solve <day> [--year <year>] [--input <path>|-] [--check [--part <1|2>]] [--timeout <seconds>]`"
+            );
+            process::exit(1);
+        }
+    };
+
+    match days::get(args.year, args.day) {
+        Some(solution) if args.check => check(solution, &args),
+        Some(solution) => {
+            advent_of_code::run(solution, args.input.as_ref(), args.timeout);
+        }
+        None => {
+            eprintln!(
+                "Day {} of {} is not registered in src/days, scaffold it with `cargo scaffold {} --year {}`.",
+                args.day, args.year, args.day, args.year
+            );
+            process::exit(1);
+        }
+    }
+}
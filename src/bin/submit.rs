@@ -0,0 +1,160 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::process;
+
+use advent_of_code::{
+    client::Client,
+    days, finish_args,
+    input::read_input,
+    runner::parse_part,
+    scaffold::parse_day,
+    solve_part,
+    submit::{self, Response},
+    verify, Answer, SolveOutcome, DEFAULT_YEAR,
+};
+
+struct Args {
+    day: u8,
+    part: u8,
+    year: i16,
+    /// The letters read from an answer that is drawn as ASCII art.
+    letters: Option<String>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        letters: args.opt_value_from_str("--letters")?,
+        day: args.free_from_fn(parse_day)?,
+        part: args.free_from_fn(parse_part)?,
+    };
+
+    finish_args(args)?;
+    Ok(parsed)
+}
+
+fn fail(message: &str, e: impl std::fmt::Display) -> ! {
+    eprintln!("{}: {}", message, e);
+    process::exit(1);
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            eprintln!("Usage: `cargo submit <day> <part> [--year <year>] [--letters <letters>]`");
+            process::exit(1);
+        }
+    };
+
+    let solution = match days::get(args.year, args.day) {
+        Some(solution) => solution,
+        None => {
+            eprintln!(
+                "Day {} of {} is not registered in src/days.",
+                args.day, args.year
+            );
+            process::exit(1);
+        }
+    };
+
+    let input =
+        read_input(args.year, args.day, None).unwrap_or_else(|e| fail("Failed to read input", e));
+    let mut recorded = verify::load(args.year, args.day)
+        .unwrap_or_else(|e| fail("Failed to load recorded answers", e));
+    let mut attempts = submit::load_attempts(args.year, args.day)
+        .unwrap_or_else(|e| fail("Failed to load attempts", e));
+
+    let result = solve_part(solution, args.part, &input);
+    let answer = match &result.value {
+        SolveOutcome::Solved(answer) => answer.clone(),
+        outcome => {
+            eprintln!(
+                "Nothing to submit, day {} part {} is {}",
+                args.day, args.part, outcome
+            );
+            process::exit(1);
+        }
+    };
+    if answer.is_multiline() {
+        println!(
+            "Day {} part {} (elapsed: {:.2?}):\n{}",
+            args.day, args.part, result.elapsed, answer
+        );
+    } else {
+        println!(
+            "Day {} part {}: {} (elapsed: {:.2?})",
+            args.day, args.part, answer, result.elapsed
+        );
+    }
+
+    if let Some(accepted) = recorded.get(args.part) {
+        if accepted.to_string() == answer.to_string() {
+            println!("✔ This answer was already accepted.");
+            return;
+        }
+        eprintln!(
+            "✘ Not submitting, the accepted answer is {}. Update or remove it in src/answers/{}/{:02}.toml if it is wrong.",
+            accepted, args.year, args.day
+        );
+        process::exit(1);
+    }
+
+    // the site expects the letters of a drawing, which only a human can read reliably.
+    let submission = match (&answer, &args.letters) {
+        (Answer::AsciiArt(_), Some(letters)) => letters.clone(),
+        (Answer::AsciiArt(_), None) => {
+            eprintln!(
+                "✘ Not submitting a drawing. Pass the letters you read with `cargo submit {} {} --letters <letters>`.",
+                args.day, args.part
+            );
+            process::exit(1);
+        }
+        (_, Some(_)) => {
+            eprintln!("✘ `--letters` is only used for answers that are drawn as ASCII art.");
+            process::exit(1);
+        }
+        (answer, None) => answer.to_string(),
+    };
+
+    let client = Client::from_env().unwrap_or_else(|e| fail("Failed to create client", e));
+    let response = submit::submit(
+        &client,
+        args.year,
+        args.day,
+        args.part,
+        &submission,
+        &mut attempts,
+    )
+    .unwrap_or_else(|e| fail("Failed to submit answer", e));
+
+    if let Err(e) = submit::save_attempts(args.year, args.day, &attempts) {
+        fail("Failed to save attempts", e);
+    }
+
+    match response {
+        Response::Correct => {
+            recorded.set(args.part, answer);
+            match verify::save(args.year, args.day, &recorded) {
+                Ok(path) => println!("⭐ Correct! Recorded answer to \"{}\"", path.display()),
+                Err(e) => fail("Answer is correct, but failed to record it", e),
+            }
+        }
+        Response::AlreadySolved => {
+            println!(
+                "This part was already solved. Run `cargo all -- --year {} --record` to record its answer if it is correct.",
+                args.year
+            );
+        }
+        response => {
+            eprintln!("✘ {}", response);
+            process::exit(1);
+        }
+    }
+}
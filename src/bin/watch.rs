@@ -0,0 +1,164 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    env,
+    ffi::OsString,
+    fs,
+    path::Path,
+    process::{self, Command},
+    thread,
+    time::Duration,
+};
+
+use advent_of_code::{
+    days, finish_args,
+    runner::{parse_part, parse_timeout},
+    scaffold::parse_day,
+    watch::{self, RunAnswers, Snapshot},
+    DEFAULT_YEAR,
+};
+
+struct Args {
+    day: u8,
+    year: i16,
+    part: Option<u8>,
+    interval: Duration,
+    timeout: Option<Duration>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        part: args.opt_value_from_fn(["-p", "--part"], parse_part)?,
+        interval: args
+            .opt_value_from_fn("--interval", watch::parse_interval)?
+            .unwrap_or(Duration::from_millis(500)),
+        timeout: args.opt_value_from_fn("--timeout", parse_timeout)?,
+        day: args.free_from_fn(parse_day)?,
+    };
+
+    finish_args(args)?;
+    Ok(parsed)
+}
+
+/// Rebuilds the solutions and checks the day in a child process, so code changes take effect.
+/// Prints the answers that changed since `previous` and returns the new answers, if the run got that far.
+fn run(args: &Args, report: &Path, previous: Option<&RunAnswers>) -> Option<RunAnswers> {
+    let _ = fs::remove_file(report);
+
+    let mut command = Command::new(env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo")));
+    command.args(["run", "--quiet"]);
+    // drop what `cargo run` set for this package, build scripts of dependencies would see it as changed and rebuild.
+    for (key, _) in env::vars_os() {
+        let key = key.to_string_lossy();
+        if key == "OUT_DIR" || key.starts_with("CARGO_PKG_") || key.starts_with("CARGO_MANIFEST_") {
+            command.env_remove(key.as_ref());
+        }
+    }
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    command
+        .args(["--bin", "solve", "--"])
+        .arg(args.day.to_string())
+        .args(["--year", &args.year.to_string(), "--check", "--report"])
+        .arg(report);
+    if let Some(part) = args.part {
+        command.args(["--part", &part.to_string()]);
+    }
+    if let Some(timeout) = args.timeout {
+        command.args(["--timeout", &timeout.as_secs_f64().to_string()]);
+    }
+
+    if let Err(e) = command.status() {
+        eprintln!("Failed to run cargo: {}", e);
+        return None;
+    }
+
+    let current = match fs::read_to_string(report) {
+        Ok(contents) => RunAnswers::parse(&contents),
+        Err(_) => {
+            println!("✘ No answers, the build or run failed.");
+            return None;
+        }
+    };
+
+    if let Some(previous) = previous {
+        let changes = watch::diff(previous, &current);
+        if changes.is_empty() {
+            println!("No answers changed since the last run.");
+        } else {
+            println!("Changed since the last run:");
+            for change in changes {
+                println!("  {}", change);
+            }
+        }
+    }
+
+    Some(current)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            eprintln!(
+                "Usage: `cargo rerun <day> [--year <year>] [--part <1|2>] [--interval <seconds>] [--timeout <seconds>]`"
+            );
+            process::exit(1);
+        }
+    };
+
+    if days::get(args.year, args.day).is_none() {
+        eprintln!(
+            "Day {} of {} is not registered in src/days, scaffold it with `cargo scaffold {} --year {}`.",
+            args.day, args.year, args.day, args.year
+        );
+        process::exit(1);
+    }
+
+    // the process id keeps watchers of the same day from overwriting each other's report.
+    let report = env::temp_dir().join(format!(
+        "aoc-watch-{}-{:02}-{}.txt",
+        args.year,
+        args.day,
+        process::id()
+    ));
+    let mut previous = None;
+    let mut snapshot = Snapshot::take(&watch::watched_paths(args.year, args.day));
+
+    loop {
+        if let Some(current) = run(&args, &report, previous.as_ref()) {
+            previous = Some(current);
+        }
+        println!("---");
+        println!(
+            "👀 Watching day {} of {} for changes, press Ctrl+C to stop.",
+            args.day, args.year
+        );
+
+        let changed = loop {
+            thread::sleep(args.interval);
+            let current = Snapshot::take(&watch::watched_paths(args.year, args.day));
+            let changed = current.changed_since(&snapshot);
+            if !changed.is_empty() {
+                snapshot = current;
+                break changed;
+            }
+        };
+
+        println!();
+        for path in changed {
+            let path = path
+                .strip_prefix(env!("CARGO_MANIFEST_DIR"))
+                .unwrap_or(&path);
+            println!("Changed \"{}\"", path.display());
+        }
+    }
+}
@@ -0,0 +1,388 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    env,
+    error::Error,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+    process,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::project_path;
+
+/// Environment variable with the session cookie of adventofcode.com.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable overriding the server, e.g. `http://127.0.0.1:8080` for a local mock server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+/// Environment variable overriding the download cache directory.
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies this tool to the Advent of Code servers, as requested by its maintainer.
+pub const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/fspoettel/advent-of-code-rust)"
+);
+
+/// Session file in the home directory, shared with aoc-cli.
+const SESSION_FILE: &str = ".adventofcode.session";
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// Neither `AOC_SESSION` nor the session file is set.
+    MissingSession {
+        session_file: Option<PathBuf>,
+    },
+    /// The server answered with an error status.
+    Status {
+        url: String,
+        status: u16,
+        body: String,
+    },
+    /// The request could not be sent or its response not be read.
+    Transport {
+        url: String,
+        message: String,
+    },
+    Io(io::Error),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::MissingSession { session_file } => {
+                write!(f, "no session cookie found, set {}", SESSION_VAR)?;
+                match session_file {
+                    Some(path) => write!(f, " or write it to \"{}\"", path.display()),
+                    None => Ok(()),
+                }
+            }
+            ClientError::Status { url, status, body } => {
+                write!(f, "{} responded with status {}", url, status)?;
+                match body.lines().next().filter(|line| !line.trim().is_empty()) {
+                    Some(line) => write!(f, ": {}", line.trim()),
+                    None => Ok(()),
+                }
+            }
+            ClientError::Transport { url, message } => {
+                write!(f, "request to {} failed: {}", url, message)
+            }
+            ClientError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for ClientError {}
+
+impl From<io::Error> for ClientError {
+    fn from(e: io::Error) -> Self {
+        ClientError::Io(e)
+    }
+}
+
+/// Reads the session cookie from `AOC_SESSION` or `~/.adventofcode.session`.
+pub fn find_session() -> Result<String, ClientError> {
+    if let Some(session) = env::var(SESSION_VAR)
+        .ok()
+        .filter(|session| !session.trim().is_empty())
+    {
+        return Ok(session.trim().to_owned());
+    }
+
+    let session_file = env::var_os("HOME").map(|home| PathBuf::from(home).join(SESSION_FILE));
+
+    session_file
+        .as_ref()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .map(|session| session.trim().to_owned())
+        .filter(|session| !session.is_empty())
+        .ok_or(ClientError::MissingSession { session_file })
+}
+
+/// Writes `contents` to a temporary file next to `path` and moves it into place,
+/// so concurrent writers never see or produce a partially written file.
+pub fn write_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    let tmp = path.with_extension(format!("{}-{}.tmp", process::id(), nanos));
+
+    fs::write(&tmp, contents)?;
+    fs::rename(&tmp, path).inspect_err(|_| {
+        let _ = fs::remove_file(&tmp);
+    })
+}
+
+/// A minimal client for adventofcode.com.
+pub struct Client {
+    base_url: String,
+    session: String,
+    cache_dir: PathBuf,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, cache_dir: PathBuf) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.to_owned(),
+            cache_dir,
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// Creates a client from `AOC_BASE_URL`, `AOC_CACHE_DIR` and the session cookie, see [`find_session`].
+    /// The cache defaults to `.aoc-cache` in the project directory.
+    pub fn from_env() -> Result<Self, ClientError> {
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let cache_dir = env::var_os(CACHE_DIR_VAR)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| project_path(".aoc-cache"));
+
+        Ok(Client::new(&base_url, &find_session()?, cache_dir))
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Location of a cached response. The session is part of the key, as inputs differ per account.
+    fn cache_path(&self, path: &str) -> PathBuf {
+        let account = format!("{:016x}", fnv1a(self.session.as_bytes()));
+        self.cache_dir
+            .join(account)
+            .join(path.trim_start_matches('/').replace('/', "_"))
+    }
+
+    /// Sends a GET request for `path` with the session cookie and returns the response body.
+    pub fn get(&self, path: &str) -> Result<String, ClientError> {
+        let url = format!("{}{}", self.base_url, path);
+        let request = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session));
+
+        read_response(&url, request.call())
+    }
+
+    /// Sends `form` url-encoded in a POST request to `path` with the session cookie and returns the response body.
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, ClientError> {
+        let url = format!("{}{}", self.base_url, path);
+        let request = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session));
+
+        read_response(&url, request.send_form(form))
+    }
+
+    /// Like [`Client::get`], but answers from the on-disk cache if `path` was fetched before.
+    pub fn get_cached(&self, path: &str) -> Result<String, ClientError> {
+        let cache_path = self.cache_path(path);
+        if let Ok(cached) = fs::read_to_string(&cache_path) {
+            return Ok(cached);
+        }
+
+        let body = self.get(path)?;
+        write_atomic(&cache_path, &body)?;
+        Ok(body)
+    }
+
+    /// Returns the HTML of the puzzle page of `day`. Pages change once part one is solved, so they are never cached.
+    pub fn puzzle(&self, year: i16, day: u8) -> Result<String, ClientError> {
+        self.get(&format!("/{}/day/{}", year, day))
+    }
+
+    /// Returns the puzzle input of `day`. Inputs never change, so every input is fetched at most once.
+    pub fn input(&self, year: i16, day: u8) -> Result<String, ClientError> {
+        self.get_cached(&format!("/{}/day/{}/input", year, day))
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, ClientError> {
+    match response {
+        Ok(response) => response.into_string().map_err(ClientError::Io),
+        Err(ureq::Error::Status(status, response)) => Err(ClientError::Status {
+            url: url.to_owned(),
+            status,
+            body: response.into_string().unwrap_or_default(),
+        }),
+        Err(e) => Err(ClientError::Transport {
+            url: url.to_owned(),
+            message: e.to_string(),
+        }),
+    }
+}
+
+/// FNV-1a, a small stable hash so session cookies do not end up in file names.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// A local stand-in for adventofcode.com that answers requests with canned responses.
+#[cfg(test)]
+pub(crate) mod mock {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    /// A request received by the mock server.
+    #[derive(Debug, Clone)]
+    pub struct Request {
+        /// e.g. `GET /2022/day/1/input`
+        pub line: String,
+        pub headers: Vec<(String, String)>,
+        pub body: String,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.as_str())
+        }
+    }
+
+    /// Serves `responses` (status and body) in order, one per connection.
+    /// Returns the base url and a receiver for the requests the server got.
+    pub fn serve(responses: Vec<(u16, String)>) -> (String, mpsc::Receiver<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+
+                let mut headers = Vec::new();
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    match header.trim_end().split_once(": ") {
+                        Some((key, value)) => headers.push((key.into(), value.into())),
+                        None => break,
+                    }
+                }
+
+                let length = headers
+                    .iter()
+                    .find(|(key, _): &&(String, String)| key.eq_ignore_ascii_case("content-length"))
+                    .map_or(0, |(_, value)| value.parse().unwrap());
+                let mut request_body = vec![0; length];
+                reader.read_exact(&mut request_body).unwrap();
+
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+
+                let _ = sender.send(Request {
+                    line: line.trim_end().into(),
+                    headers,
+                    body: String::from_utf8(request_body).unwrap(),
+                });
+            }
+        });
+
+        (base_url, receiver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp::TempPath;
+
+    #[test]
+    fn test_input_is_cached() {
+        let (base_url, requests) = mock::serve(vec![(200, "1\n2\n".into())]);
+        let cache_dir = TempPath::new("client-cache");
+        let client = Client::new(&base_url, "secret", cache_dir.to_path_buf());
+
+        assert_eq!(client.input(2022, 1).unwrap(), "1\n2\n");
+        // the mock only answers once, a second request would fail.
+        assert_eq!(client.input(2022, 1).unwrap(), "1\n2\n");
+
+        let request = requests.recv().unwrap();
+        assert_eq!(request.line, "GET /2022/day/1/input HTTP/1.1");
+        assert_eq!(request.header("cookie"), Some("session=secret"));
+        assert_eq!(request.header("user-agent"), Some(USER_AGENT));
+    }
+
+    #[test]
+    fn test_puzzle_is_not_cached() {
+        let (base_url, requests) =
+            mock::serve(vec![(200, "part one".into()), (200, "part two".into())]);
+        let cache_dir = TempPath::new("client-puzzle");
+        let client = Client::new(&base_url, "secret", cache_dir.to_path_buf());
+
+        assert_eq!(client.puzzle(2022, 3).unwrap(), "part one");
+        assert_eq!(client.puzzle(2022, 3).unwrap(), "part two");
+        assert!(!cache_dir.exists());
+        assert_eq!(requests.recv().unwrap().line, "GET /2022/day/3 HTTP/1.1");
+    }
+
+    #[test]
+    fn test_error_status() {
+        let (base_url, _requests) = mock::serve(vec![(
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n".into(),
+        )]);
+        let cache_dir = TempPath::new("client-error");
+        let client = Client::new(&base_url, "expired", cache_dir.to_path_buf());
+
+        let error = client.input(2022, 2).unwrap_err();
+        assert!(!cache_dir.exists());
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "{}/2022/day/2/input responded with status 400: Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+                base_url
+            )
+        );
+    }
+
+    #[test]
+    fn test_write_atomic() {
+        let dir = TempPath::new("client-atomic");
+        let path = dir.join("nested").join("01.txt");
+
+        write_atomic(&path, "first").unwrap();
+        write_atomic(&path, "second").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "second");
+        assert_eq!(fs::read_dir(path.parent().unwrap()).unwrap().count(), 1);
+    }
+}
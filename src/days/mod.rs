@@ -0,0 +1,53 @@
+/*
+ * Registry of all implemented days, with a module per year.
+ * `cargo scaffold` adds a `pub mod yYYYY;` declaration and an entry to `YEARS` for the first solution of a year.
+ */
+use crate::Solution;
+
+pub mod y2022;
+
+/// The solutions of every year, ordered by year.
+// one entry per line, so `cargo scaffold` can add years.
+#[rustfmt::skip]
+pub static YEARS: &[&[&dyn Solution]] = &[
+    y2022::ALL,
+];
+
+/// Returns all implemented solutions, ordered by year and day.
+pub fn all() -> impl Iterator<Item = &'static dyn Solution> {
+    YEARS.iter().flat_map(|solutions| solutions.iter().copied())
+}
+
+/// Returns the implemented solutions of `year`, ordered by day.
+pub fn year(year: i16) -> impl Iterator<Item = &'static dyn Solution> {
+    all().filter(move |solution| solution.year() == year)
+}
+
+/// Returns the registered solution for `day` of `year`, if there is one.
+pub fn get(year: i16, day: u8) -> Option<&'static dyn Solution> {
+    all().find(|solution| solution.year() == year && solution.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_is_ordered() {
+        let days: Vec<(i16, u8)> = all()
+            .map(|solution| (solution.year(), solution.day()))
+            .collect();
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]), "{:?}", days);
+    }
+
+    #[test]
+    fn test_get() {
+        assert_eq!(
+            get(2022, 5).map(|solution| solution.title()),
+            Some("Supply Stacks")
+        );
+        assert!(get(2022, 25).is_none());
+        assert!(get(2015, 5).is_none());
+        assert_eq!(year(2022).count(), y2022::ALL.len());
+    }
+}
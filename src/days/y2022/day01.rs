@@ -0,0 +1,33 @@
+/// Returns an iterator that iterates over the sum of all calories per elf.
+fn parse_calories(input: &str) -> impl Iterator<Item = u32> + '_ {
+    // I really wanted a declarative solution here.
+    // The 'filter' at the end is not really necessary, but without it the resulting iterator will contain a bunch of ugly 0 values.
+    input
+        .split("\n")
+        .scan(0_u32, |state, item| {
+            if let Ok(calories) = item.parse::<u32>() {
+                *state += calories;
+                Some(0)
+            } else {
+                let result = Some(*state);
+                *state = 0;
+                result
+            }
+        })
+        .filter(|calories| *calories != 0)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    parse_calories(input).max()
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let mut calories: Vec<u32> = parse_calories(input).collect();
+    calories.sort_by(|a, b| b.cmp(a)); // sort in reverse order (from max -> min)
+    let max_sum: u32 = calories.iter().take(3).sum();
+    Some(max_sum)
+}
+
+crate::solution!(Day01, 2022, 1, "Calorie Counting");
+
+// Other similar solution I like: https://github.com/fspoettel/advent-of-code-2022/blob/main/src/bin/01.rs
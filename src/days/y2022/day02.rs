@@ -0,0 +1,130 @@
+use std::str::FromStr;
+
+use crate::parse::ParseError;
+
+#[derive(PartialEq)]
+enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(PartialEq)]
+enum Outcome {
+    Lost = 0,
+    Draw = 3,
+    Won = 6,
+}
+
+#[derive(PartialEq)]
+enum Strategy {
+    /// Opponent based.
+    One,
+    /// Outcome based.
+    Two,
+}
+
+impl Shape {
+    fn to_score(&self) -> u32 {
+        match self {
+            Shape::Rock => 1,
+            Shape::Paper => 2,
+            Shape::Scissors => 3,
+        }
+    }
+}
+
+impl FromStr for Shape {
+    type Err = ParseError;
+
+    fn from_str(letter: &str) -> Result<Self, Self::Err> {
+        match letter {
+            "X" | "A" => Ok(Shape::Rock),
+            "Y" | "B" => Ok(Shape::Paper),
+            "Z" | "C" => Ok(Shape::Scissors),
+            _ => Err(ParseError::new(
+                letter,
+                "expected a shape (A, B, C, X, Y or Z)",
+            )),
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = ParseError;
+
+    fn from_str(letter: &str) -> Result<Self, Self::Err> {
+        match letter {
+            "X" => Ok(Outcome::Lost),
+            "Y" => Ok(Outcome::Draw),
+            "Z" => Ok(Outcome::Won),
+            _ => Err(ParseError::new(letter, "expected an outcome (X, Y or Z)")),
+        }
+    }
+}
+
+fn play_game(opponent: Shape, player: Shape) -> Outcome {
+    // I should've used a modulus (%) here and below instead :S
+    if opponent == player {
+        Outcome::Draw
+    } else if (player == Shape::Rock && opponent == Shape::Scissors)
+        || (player == Shape::Scissors && opponent == Shape::Paper)
+        || (player == Shape::Paper && opponent == Shape::Rock)
+    {
+        Outcome::Won
+    } else {
+        Outcome::Lost
+    }
+}
+
+fn predict_shape(opponent: Shape, outcome: Outcome) -> Shape {
+    match outcome {
+        Outcome::Draw => opponent,
+        Outcome::Lost => match opponent {
+            Shape::Rock => Shape::Scissors,
+            Shape::Paper => Shape::Rock,
+            Shape::Scissors => Shape::Paper,
+        },
+        Outcome::Won => match opponent {
+            Shape::Rock => Shape::Paper,
+            Shape::Paper => Shape::Scissors,
+            Shape::Scissors => Shape::Rock,
+        },
+    }
+}
+
+fn play_round(left: &str, right: &str, strategy: &Strategy) -> Result<u32, ParseError> {
+    let mut my_score: u32 = 0;
+    let opponent: Shape = left.parse()?;
+    let me = if *strategy == Strategy::One {
+        right.parse()?
+    } else {
+        predict_shape(left.parse()?, right.parse()?)
+    };
+    my_score += me.to_score();
+    my_score += play_game(opponent, me) as u32;
+    Ok(my_score)
+}
+
+fn play(input: &str, strategy: Strategy) -> Result<u32, ParseError> {
+    let mut my_total_score = 0;
+
+    for line in input.lines() {
+        let (opponent, me) = line
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(line, "expected two letters separated by a space"))?;
+        my_total_score += play_round(opponent, me, &strategy)?;
+    }
+
+    Ok(my_total_score)
+}
+
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    play(input, Strategy::One).map_err(|e| e.locate(input))
+}
+
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
+    play(input, Strategy::Two).map_err(|e| e.locate(input))
+}
+
+crate::solution!(Day02, 2022, 2, "Rock Paper Scissors");
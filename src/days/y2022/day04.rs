@@ -0,0 +1,51 @@
+use crate::helpers::scan;
+use crate::parse::ParseError;
+
+type Sections = (u32, u32);
+
+/// Parses a pair of section ranges like `2-4,6-8`.
+fn parse_pair(line: &str) -> Result<(Sections, Sections), ParseError> {
+    let (a, b, c, d) = scan::pattern(line, "{}-{},{}-{}")?;
+    Ok(((a, b), (c, d)))
+}
+
+fn is_overlapping(x: Sections, y: Sections) -> bool {
+    x.0.max(y.0) <= x.1.min(y.1)
+}
+
+fn is_contained(x: Sections, y: Sections) -> bool {
+    // Another way I found I can solve this is via a variable flip:
+    // if y.1 >= x.1 && y.0 <= x.0 {
+    //     (y, x) = (x, y);
+    // }
+    // y.0 >= x.0 && y.1 <= x.1
+    (y.0 >= x.0 && y.1 <= x.1) || (x.0 >= y.0 && x.1 <= y.1)
+}
+
+fn solve(input: &str, part: u32) -> Result<u32, ParseError> {
+    let f = if part == 1 {
+        is_contained
+    } else {
+        is_overlapping
+    };
+
+    let mut count = 0;
+    for line in input.lines() {
+        let (left, right) = parse_pair(line).map_err(|e| e.locate(input))?;
+        if f(left, right) {
+            count += 1;
+        }
+    }
+
+    Ok(count)
+}
+
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    solve(input, 1)
+}
+
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
+    solve(input, 2)
+}
+
+crate::solution!(Day04, 2022, 4, "Camp Cleanup");
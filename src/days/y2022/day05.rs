@@ -0,0 +1,165 @@
+use std::collections::{btree_map::Entry, BTreeMap, VecDeque};
+use std::str::FromStr;
+
+use crate::helpers::scan;
+use crate::parse::{self, ParseError};
+
+// NOTE: Parsing the header took me way more time than parsing the steps and solving the actual challenge.
+// I started by hard-coding the crates and solved the actual challenge first.
+// Then I got to parsing the header but it took too much time so I ended up taking some inspiration from https://github.com/believer/advent-of-code/blob/master/rust/2022/src/day_05.rs which has a similar approach I had in mind.
+
+struct Step {
+    to: usize,
+    from: usize,
+    count: usize,
+}
+
+type Stacks = BTreeMap<usize, VecDeque<String>>;
+/// The steps with the line each was parsed from, to point errors at it.
+type Steps<'a> = Vec<(&'a str, Step)>;
+
+/// Parses the number of a stack, which are numbered from 1 in the input.
+fn parse_stack(s: &str) -> Result<usize, ParseError> {
+    match parse::number::<usize>(s)? {
+        0 => Err(ParseError::new(s, "stacks are numbered from 1")),
+        stack => Ok(stack - 1),
+    }
+}
+
+impl FromStr for Step {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (count, from, to) = scan::pattern(input, "move {} from {} to {}")?;
+
+        Ok(Step {
+            to: parse_stack(to)?,
+            from: parse_stack(from)?,
+            count,
+        })
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Stacks, Steps<'_>), ParseError> {
+    let mut map: Stacks = BTreeMap::new();
+
+    let mut blocks = scan::blocks(input);
+    let (Some(crates), Some(steps)) = (blocks.next(), blocks.next()) else {
+        return Err(ParseError::new(
+            input,
+            "expected the stacks and the steps separated by a blank line",
+        ));
+    };
+
+    let mut rows: Vec<&str> = crates.lines().collect();
+    // the last line numbers the stacks.
+    let numbers = rows
+        .pop()
+        .ok_or_else(|| ParseError::new(crates, "expected a drawing of the stacks"))?;
+
+    // every numbered stack exists, even if it starts out empty.
+    for i in 0..numbers.split_whitespace().count() {
+        map.insert(i, VecDeque::new());
+    }
+
+    // parse the crates
+    for row in rows {
+        for (i, column) in row.chars().collect::<Vec<char>>().chunks(4).enumerate() {
+            // find the name of the crate
+            let value: String = column
+                .iter()
+                .map(|s| s.to_string().trim().replace(['[', ']'], ""))
+                .collect::<Vec<String>>()
+                .join("");
+
+            // skip empty columns
+            if value.is_empty() {
+                continue;
+            }
+
+            // add to or create the stack
+            match map.entry(i) {
+                Entry::Vacant(e) => {
+                    e.insert(VecDeque::from_iter([value]));
+                }
+                Entry::Occupied(mut e) => {
+                    e.get_mut().push_back(value);
+                }
+            }
+        }
+    }
+
+    // parse the instructions
+    let mut instructions = Vec::new();
+    for line in steps.lines() {
+        let step: Step = line.parse()?;
+        for stack in [step.from, step.to] {
+            if !map.contains_key(&stack) {
+                return Err(ParseError::new(
+                    line,
+                    format!("there is no stack {}", stack + 1),
+                ));
+            }
+        }
+        instructions.push((line, step));
+    }
+
+    Ok((map, instructions))
+}
+
+fn find_first_crates(stacks: Stacks) -> String {
+    stacks
+        .values()
+        .filter_map(|v| v.front())
+        .cloned()
+        .collect::<Vec<String>>()
+        .join("")
+}
+
+/// Removes the crates a step moves from its source stack, topmost first.
+fn take_crates(
+    stacks: &mut Stacks,
+    line: &str,
+    step: &Step,
+) -> Result<VecDeque<String>, ParseError> {
+    // both stacks of a step are checked to exist while parsing.
+    let stack = stacks.get_mut(&step.from).unwrap();
+    if stack.len() < step.count {
+        let message = format!("stack {} only holds {} crates", step.from + 1, stack.len());
+        return Err(ParseError::new(line, message));
+    }
+
+    Ok(stack.drain(..step.count).collect())
+}
+
+pub fn part_one(input: &str) -> Result<String, ParseError> {
+    let (mut stacks, steps) = parse_input(input).map_err(|e| e.locate(input))?;
+
+    for (line, step) in steps {
+        // Moving crates one at a time puts the topmost crate at the bottom.
+        for crate_ in take_crates(&mut stacks, line, &step).map_err(|e| e.locate(input))? {
+            stacks.get_mut(&step.to).unwrap().push_front(crate_);
+        }
+    }
+
+    Ok(find_first_crates(stacks))
+}
+
+pub fn part_two(input: &str) -> Result<String, ParseError> {
+    let (mut stacks, steps) = parse_input(input).map_err(|e| e.locate(input))?;
+
+    for (line, step) in steps {
+        // Remove all crates that should be moved from the source stack.
+        // Reverse the order since we're adding them to the front one by one.
+        let stack = take_crates(&mut stacks, line, &step).map_err(|e| e.locate(input))?;
+
+        // Add the crates to the destination stack
+        for crate_ in stack.into_iter().rev() {
+            stacks.get_mut(&step.to).unwrap().push_front(crate_);
+        }
+    }
+
+    Ok(find_first_crates(stacks))
+}
+
+crate::solution!(Day05, 2022, 5, "Supply Stacks");
@@ -0,0 +1,36 @@
+use itertools::Itertools;
+
+use crate::parse::ParseError;
+
+// another cool and different solution I like: https://github.com/holly-hacker/AdventOfCode2022/blob/main/src/day06/mod.rs#L26
+
+fn find_marker(line: &str, marker_length: usize) -> Option<u32> {
+    for (i, _) in line.chars().enumerate() {
+        let n = i + 1;
+        let marker = line.chars().skip(n).take(marker_length); // this probably isn't the most efficient
+        if marker.sorted().dedup().count() == marker_length {
+            return Some((n + marker_length) as u32);
+        }
+    }
+
+    None
+}
+
+/// Finds the end of the first marker of `marker_length` different characters in the first line of `input`.
+fn solve(input: &str, marker_length: usize) -> Result<u32, ParseError> {
+    let line = input.lines().next().unwrap_or_default();
+    find_marker(line, marker_length).ok_or_else(|| {
+        let message = format!("expected {} different characters in a row", marker_length);
+        ParseError::new(line, message).locate(input)
+    })
+}
+
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    solve(input, 4)
+}
+
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
+    solve(input, 14)
+}
+
+crate::solution!(Day06, 2022, 6, "Tuning Trouble");
@@ -0,0 +1,148 @@
+use std::collections::HashSet;
+
+use crate::parse::{self, ParseError};
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+struct File {
+    name: String,
+    size: usize,
+    children: Vec<File>,
+}
+
+impl From<&str> for File {
+    fn from(name: &str) -> Self {
+        Self {
+            name: String::from(name),
+            size: 0,
+            children: Vec::new(),
+        }
+    }
+}
+
+impl File {
+    /// Find a child directory by its paths, relative to the current parent.
+    /// Directories that were not listed yet are created on the way.
+    fn find_dir(&mut self, paths: &[String]) -> &mut Self {
+        let mut current = self;
+
+        for path in paths {
+            let index = match current.children.iter().position(|f| &f.name == path) {
+                Some(index) => index,
+                None => {
+                    current.children.push(File::from(path.as_str()));
+                    current.children.len() - 1
+                }
+            };
+            current = &mut current.children[index];
+        }
+
+        current
+    }
+
+    /// Checks whether a child `File` exists at the specified path.
+    fn has_child(&mut self, path: &str) -> bool {
+        self.children.iter().any(|x| x.name == path)
+    }
+
+    /// Recursively calculate the size of the current file and its children.
+    /// (We could also do this at runtime but since the file tree never changes it makes more sense to calculate it only once)
+    fn calculate_sizes(&mut self) -> usize {
+        for file in &mut self.children {
+            self.size += file.calculate_sizes();
+        }
+
+        self.size
+    }
+
+    /// Returns all child files recursively.
+    fn get_files(&self) -> HashSet<Self> {
+        let mut files = HashSet::new();
+
+        for child in &self.children {
+            files.insert(child.clone());
+            files.extend(child.get_files());
+        }
+
+        files
+    }
+
+    /// Whether it's a directory.
+    fn is_dir(&self) -> bool {
+        !self.children.is_empty()
+    }
+}
+
+fn parse_file_tree(raw: &str) -> Result<File, ParseError> {
+    let mut tree = File::from("/");
+    let mut path = Vec::new();
+
+    for line in raw.lines() {
+        match line.split_whitespace().collect::<Vec<_>>()[..] {
+            ["$", "cd", "/"] => path.clear(),
+            ["$", "cd", ".."] => {
+                if path.pop().is_none() {
+                    return Err(ParseError::new(line, "can't leave the root directory").locate(raw));
+                }
+            }
+            ["$", "cd", dir_name] => path.push(dir_name.to_owned()),
+            ["$", "ls"] => {}
+            ["$", ..] => {
+                return Err(ParseError::new(line, "expected a command (cd or ls)").locate(raw))
+            }
+            ["dir", dir_name] => {
+                let parent = tree.find_dir(&path);
+
+                if !parent.has_child(dir_name) {
+                    parent.children.push(File::from(dir_name));
+                }
+            }
+            [size, file_name] => {
+                let mut child = File::from(file_name);
+                child.size = parse::number(size).map_err(|e| e.locate(raw))?;
+
+                tree.find_dir(&path).children.push(child);
+            }
+            _ => return Err(ParseError::new(line, "expected a directory or a file").locate(raw)),
+        }
+    }
+
+    tree.calculate_sizes();
+
+    Ok(tree)
+}
+
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    let size = parse_file_tree(input)?
+        .get_files()
+        .iter()
+        .filter(|file| file.is_dir() && file.size <= 100000)
+        .fold(0, |x, y| x + y.size);
+
+    Ok(size as u32)
+}
+
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
+    let tree = parse_file_tree(input)?;
+    let end = &input[input.len()..];
+
+    let free = 70_000_000_usize.checked_sub(tree.size).ok_or_else(|| {
+        ParseError::new(end, "expected the files to fit on the disk").locate(input)
+    })?;
+    let space = 30_000_000_usize.saturating_sub(free);
+
+    let children = tree.get_files();
+    let mut children_mut = children.iter().collect::<Vec<_>>();
+    children_mut.sort_by_key(|a| a.size);
+
+    let size = children_mut
+        .iter()
+        .find(|x| x.is_dir() && x.size > space)
+        .ok_or_else(|| {
+            ParseError::new(end, "expected a directory large enough to free up space").locate(input)
+        })?
+        .size;
+
+    Ok(size as u32)
+}
+
+crate::solution!(Day07, 2022, 7, "No Space Left On Device");
@@ -0,0 +1,64 @@
+use crate::{
+    helpers::{geom::Direction4, Grid},
+    ParseError,
+};
+
+fn parse_grid(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse(input, |c| c.to_digit(10))
+}
+
+fn is_visible(current: &u32, mut range: impl Iterator<Item = u32>) -> bool {
+    range.all(|v| v < *current)
+}
+
+fn get_distance(current: &u32, range: impl Iterator<Item = u32>) -> u32 {
+    let mut distance = 0;
+
+    for i in range {
+        if i < *current {
+            distance += 1;
+        } else if i >= *current {
+            distance += 1;
+            break;
+        }
+    }
+
+    distance
+}
+
+fn process(input: &str, part_two: bool) -> Result<u32, ParseError> {
+    let mut total = 0;
+    let grid = parse_grid(input)?;
+
+    for (pos, tree) in grid.iter() {
+        // the trees from this one to each edge, closest first.
+        // edges have an empty range in at least one direction, so they are always visible.
+        let ranges = Direction4::ALL.map(|direction| grid.ray(pos, direction).map(|(_, v)| *v));
+
+        if !part_two {
+            if ranges.into_iter().any(|range| is_visible(tree, range)) {
+                total += 1;
+            }
+        } else {
+            let scenic_score = ranges
+                .into_iter()
+                .map(|range| get_distance(tree, range))
+                .product();
+            if scenic_score > total {
+                total = scenic_score;
+            }
+        }
+    }
+
+    Ok(total)
+}
+
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    process(input, false)
+}
+
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
+    process(input, true)
+}
+
+crate::solution!(Day08, 2022, 8, "Treetop Tree House");
@@ -0,0 +1,79 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use crate::helpers::geom::{Direction4, Point2};
+use crate::parse::{self, ParseError};
+
+type Point = Point2<i32>;
+
+/// A line of the input: move the head `count` steps in `direction`.
+struct Motion {
+    direction: Direction4,
+    count: usize,
+}
+
+impl FromStr for Motion {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (direction, count) = line
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(line, "expected a direction and a number of steps"))?;
+
+        Ok(Motion {
+            direction: direction.parse()?,
+            count: parse::number(count)?,
+        })
+    }
+}
+
+fn solve(input: &str, length: usize) -> Result<u32, ParseError> {
+    let motions: Vec<Motion> = parse::lines(input)?;
+
+    // we start our knotting yourney at the bottom left of our 2D grid (x=0, y=0).
+    let start_point = Point::new(0, 0);
+
+    // we also have `length` knots as part of the rope (first part: 1 head + 1 tail)
+    // we store their curent positions in this array.
+    let mut knots = vec![start_point; length];
+
+    // an array containing all `Point`s the tail has been.
+    // we specifically use a hashset so no duplicates are inserted.
+    let mut tail_points = HashSet::new();
+    tail_points.insert(start_point);
+
+    for Motion { direction, count } in motions {
+        for _ in 0..count {
+            // move the head `count` amount of times in the `direction`
+            knots[0] += direction.offset();
+
+            // move the rest of the knots `count` amount of times in the `direction`
+            for i in 1..knots.len() {
+                // diff between previous knot and current knot
+                let diff = knots[i - 1] - knots[i];
+
+                // if the head is already right next to the tail, continue
+                if knots[i - 1].chebyshev(knots[i]) <= 1 {
+                    continue;
+                }
+
+                // update current knot's position
+                knots[i] += diff.signum();
+            }
+
+            tail_points.insert(*knots.last().unwrap());
+        }
+    }
+
+    Ok(tail_points.len() as u32)
+}
+
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    solve(input, 2)
+}
+
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
+    solve(input, 10)
+}
+
+crate::solution!(Day09, 2022, 9, "Rope Bridge");
@@ -0,0 +1,129 @@
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+use crate::helpers::scan;
+use crate::parse::{self, ParseError};
+use crate::Answer;
+
+#[derive(Clone, Copy)]
+enum Op {
+    Noop,
+    Addx(i32),
+}
+
+impl FromStr for Op {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "noop" => Ok(Self::Noop),
+            value => Ok(Self::Addx(scan::pattern(value, "addx {}")?)),
+        }
+    }
+}
+
+impl Op {
+    fn execution_cycle(&self) -> usize {
+        match self {
+            Op::Noop => 1,
+            Op::Addx(_) => 2,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Instruction {
+    /// The operation to execute.
+    op: Op,
+
+    /// Number of the cycle when this instruction should execute.
+    cycle: usize,
+}
+
+impl Instruction {
+    fn can_run(&self, cycle: usize) -> bool {
+        cycle == self.cycle
+    }
+}
+
+/// Runs the program and returns the sum of the signal strengths and the rows drawn by the CRT.
+fn solve(input: &str) -> Result<(i32, Vec<String>), ParseError> {
+    let mut iter = parse::lines::<Op>(input)?.into_iter();
+    let mut x: i32 = 1;
+    let mut cycle = 0;
+    let mut total = 0;
+    let mut callstack: VecDeque<Instruction> = VecDeque::new();
+    let mut pixels: Vec<&str> = Vec::new();
+    let mut screen: Vec<String> = Vec::new();
+
+    // What I had in mind initially was to parse each instruction at runtime an expand the amount of cycles in the loop as we continue to read the input line by line.
+    // But Rust doesn't allow modifications to a range while looping over it!
+    // Thus this while loop instead.
+    // Alternatively we could create a fn to count the number of cycles beforehand, though this isn't really required (as of part 1).
+    // Perhaps my initial idea is still possible some other way though, but I haven't discovered that solution yet...
+    loop {
+        // Parse next instruction.
+        if let Some(op) = iter.next() {
+            let mut instruction = Instruction { op, cycle };
+
+            if let Some(last_instruction) = callstack.back() {
+                instruction.cycle = last_instruction.cycle;
+            }
+
+            instruction.cycle += op.execution_cycle();
+
+            callstack.push_back(instruction);
+        }
+
+        // We're done if there are no more instructions to execute.
+        if callstack.is_empty() {
+            break;
+        }
+
+        // Totals must be calculated at the START of this cycle.
+        if (cycle as i32 - 20) % 40 == 0 {
+            let signal_strength = x * cycle as i32;
+            total += signal_strength;
+        }
+
+        // Execute instructions at the END of this cycle.
+        for instruction in callstack.iter() {
+            if !instruction.can_run(cycle) {
+                continue;
+            }
+
+            if let Op::Addx(value) = instruction.op {
+                x += value;
+            };
+        }
+
+        // The CRT draws a pixel during each cycle.
+        let pos = cycle as i32 % 40;
+        if pos - 1 == x || pos == x || pos + 1 == x {
+            pixels.push("#");
+        } else {
+            pixels.push(".");
+        }
+        if (cycle + 1) % 40 == 0 {
+            screen.push(pixels.join(""));
+            pixels.clear();
+        }
+
+        // Clean the stack by removing executed instructions.
+        callstack.retain(|instruction| !instruction.can_run(cycle));
+
+        cycle += 1;
+    }
+
+    Ok((total, screen))
+}
+
+pub fn part_one(input: &str) -> Result<i32, ParseError> {
+    Ok(solve(input)?.0)
+}
+
+pub fn part_two(input: &str) -> Result<Answer, ParseError> {
+    Ok(Answer::ascii_art(solve(input)?.1))
+}
+
+crate::solution!(Day10, 2022, 10, "Cathode-Ray Tube");
@@ -0,0 +1,238 @@
+use itertools::Itertools;
+use std::{cmp::Reverse, collections::VecDeque, str::FromStr};
+
+use crate::helpers::scan;
+use crate::parse::{self, ParseError};
+
+#[derive(Debug, Clone)]
+struct Test {
+    divisible_by: u64,
+    if_true: usize,
+    if_false: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Operand {
+    Old,
+    Number(u64),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Operator {
+    Add,
+    Multiply,
+}
+
+/// How a monkey changes the worry level of an item, e.g. `old * 19`.
+#[derive(Debug, Clone, Copy)]
+struct Operation {
+    left: Operand,
+    operator: Operator,
+    right: Operand,
+}
+
+#[derive(Debug, Clone)]
+struct Monkey {
+    inspections: usize,
+    items: VecDeque<u64>,
+    operation: Operation,
+    test: Test,
+}
+
+enum DivisionStrategy {
+    DivideByThree,
+    Magic(u64),
+}
+
+/// The description of a monkey, from its header to where it throws items.
+const MONKEY: &str = "Monkey {}:
+  Starting items:{}
+  Operation: new = {}
+  Test: divisible by {}
+    If true: throw to monkey {}
+    If false: throw to monkey {}";
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let operand = |v: &str| match v {
+            "old" => Some(Operand::Old),
+            v => v.parse().ok().map(Operand::Number),
+        };
+        let operator = |v: &str| match v {
+            "+" => Some(Operator::Add),
+            "*" => Some(Operator::Multiply),
+            _ => None,
+        };
+
+        let parts = match s.split_whitespace().collect_vec()[..] {
+            [left, op, right] => operand(left).zip(operator(op)).zip(operand(right)),
+            _ => None,
+        };
+        let ((left, operator), right) =
+            parts.ok_or_else(|| ParseError::new(s, "expected an operation like \"old * 19\""))?;
+
+        Ok(Operation {
+            left,
+            operator,
+            right,
+        })
+    }
+}
+
+impl Operation {
+    fn apply(&self, old: u64) -> u64 {
+        let value = |operand| match operand {
+            Operand::Old => old,
+            Operand::Number(number) => number,
+        };
+
+        match self.operator {
+            Operator::Add => value(self.left) + value(self.right),
+            Operator::Multiply => value(self.left) * value(self.right),
+        }
+    }
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // monkeys are listed in order, so their number in the header can be ignored.
+        let (_, starting_items, operation, divisible_by, if_true, if_false): (
+            &str,
+            &str,
+            &str,
+            &str,
+            usize,
+            usize,
+        ) = scan::pattern(s, MONKEY)?;
+        let divisible_by = match parse::number(divisible_by)? {
+            0 => {
+                return Err(ParseError::new(
+                    divisible_by.trim(),
+                    "expected a divisor greater than 0",
+                ))
+            }
+            divisor => divisor,
+        };
+        let starting_items = scan::ints::<u64>(starting_items)?;
+        let operation = operation.parse()?;
+
+        Ok(Monkey {
+            inspections: 0,
+            items: VecDeque::from(starting_items),
+            operation,
+            test: Test {
+                divisible_by,
+                if_true,
+                if_false,
+            },
+        })
+    }
+}
+
+impl Monkey {
+    /// Inspect a single item with a specific worry level.
+    /// Returns the number of the next Monkey this item should be thrown to and the new worry level.
+    fn inspect_item(&self, item: &u64, division: &DivisionStrategy) -> (usize, u64) {
+        let mut worry_level = self.operation.apply(*item);
+
+        match division {
+            DivisionStrategy::DivideByThree => {
+                worry_level = ((worry_level / 3) as f64).round() as u64;
+            }
+            DivisionStrategy::Magic(nr) => {
+                worry_level %= nr;
+            }
+        }
+
+        if worry_level.is_multiple_of(self.test.divisible_by) {
+            (self.test.if_true, worry_level)
+        } else {
+            (self.test.if_false, worry_level)
+        }
+    }
+}
+
+fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let blocks: Vec<&str> = scan::blocks(input).collect();
+    let monkeys = blocks
+        .iter()
+        .map(|block| block.parse())
+        .collect::<Result<Vec<Monkey>, _>>()
+        .map_err(|e| e.locate(input))?;
+
+    // the two most active monkeys are multiplied.
+    if monkeys.len() < 2 {
+        return Err(ParseError::new(input, "expected at least two monkeys").locate(input));
+    }
+
+    for (block, monkey) in blocks.iter().zip(&monkeys) {
+        for target in [monkey.test.if_true, monkey.test.if_false] {
+            if target >= monkeys.len() {
+                let message = format!(
+                    "throws to monkey {}, but there are only {} monkeys",
+                    target,
+                    monkeys.len()
+                );
+                return Err(ParseError::new(block, message).locate(input));
+            }
+        }
+    }
+
+    Ok(monkeys)
+}
+
+fn play_round(monkeys: &mut [Monkey], division: &DivisionStrategy) {
+    for i in 0..monkeys.len() {
+        while let Some(item) = monkeys[i].items.pop_front() {
+            let (next_monkey, worry_level) = monkeys[i].inspect_item(&item, division);
+
+            // throw item with new worry level to next monkey
+            monkeys[next_monkey].items.push_back(worry_level);
+
+            // increase amount of inspections
+            monkeys[i].inspections += 1;
+        }
+    }
+}
+
+fn play_rounds(monkeys: &mut [Monkey], count: usize, division: DivisionStrategy) {
+    for _ in 0..count {
+        play_round(monkeys, &division);
+    }
+}
+
+fn find_most_active(mut monkeys: Vec<Monkey>) -> usize {
+    // sort in descending order
+    monkeys.sort_by_key(|m| Reverse(m.inspections));
+    // return product of 2 most active monkeys
+    monkeys[0].inspections * monkeys[1].inspections
+}
+
+pub fn part_one(input: &str) -> Result<usize, ParseError> {
+    let mut monkeys = parse_monkeys(input)?;
+
+    play_rounds(&mut monkeys, 20, DivisionStrategy::DivideByThree);
+
+    Ok(find_most_active(monkeys))
+}
+
+pub fn part_two(input: &str) -> Result<usize, ParseError> {
+    let mut monkeys = parse_monkeys(input)?;
+
+    // In order to solve part 2, we have to 'find another way to keep your worry levels manageable'.
+    // At first I tried to use BigInts (using the bigint-num crate), but that didn't work because the numbers would grow so big it would massively slow down the program at around 500 rounds.
+    // I had to look this part up because the correct solution seems to be general knowledge to those who've encountered a similar problem before. And I'm also not a mathematician.
+    // TL;DR: The idea here is that all the monkeys are doing modulo with the product of all divisors (which happen to be prime numbers) against you worry level.
+    // More info here: https://fasterthanli.me/series/advent-of-code-2022/part-11#math-check
+    let magic_nr = monkeys.iter().map(|x| x.test.divisible_by).product::<u64>();
+
+    play_rounds(&mut monkeys, 10000, DivisionStrategy::Magic(magic_nr));
+
+    Ok(find_most_active(monkeys))
+}
+
+crate::solution!(Day11, 2022, 11, "Monkey in the Middle");
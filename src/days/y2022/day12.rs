@@ -0,0 +1,129 @@
+// I really dislike implementing existing pathfinding algos from scratch (what's fun about reinventing the wheel??)
+// so this uses the breadth-first search from `helpers::search`: every step costs the same.
+// I'm hoping day 13 will be more fun...
+
+use std::str::FromStr;
+
+use crate::{
+    helpers::{search, Grid, Pos},
+    ParseError,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Mountain {
+    height: u8,
+    _type: MountainType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum MountainType {
+    Start,
+    End,
+    Mountain,
+}
+
+impl Mountain {
+    fn from_char(value: char) -> Option<Self> {
+        match value {
+            'S' => Some(Mountain {
+                height: 0,
+                _type: MountainType::Start,
+            }),
+            'E' => Some(Mountain {
+                height: 25,
+                _type: MountainType::End,
+            }),
+            c if c.is_ascii_lowercase() => Some(Mountain {
+                height: value as u8 - b'a',
+                _type: MountainType::Mountain,
+            }),
+            _ => None,
+        }
+    }
+
+    fn can_reach(&self, other: &Mountain) -> bool {
+        other.height.saturating_sub(self.height) <= 1
+    }
+}
+
+struct MountainMap {
+    mountains: Grid<Mountain>,
+    start_at: Pos,
+    end_at: Pos,
+}
+
+impl FromStr for MountainMap {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mountains = Grid::parse(value, Mountain::from_char)?;
+
+        // When we encounter the start or end mountains, we mark those as special.
+        let find = |_type, name| {
+            mountains
+                .position(|mountain| mountain._type == _type)
+                .ok_or_else(|| {
+                    ParseError::new(
+                        &value[value.len()..],
+                        format!("expected the {} on the map", name),
+                    )
+                    .locate(value)
+                })
+        };
+        let start_at = find(MountainType::Start, "start (S)")?;
+        let end_at = find(MountainType::End, "end (E)")?;
+
+        Ok(MountainMap {
+            mountains,
+            start_at,
+            end_at,
+        })
+    }
+}
+
+impl MountainMap {
+    /// The positions we can climb to in a single step from `pos`.
+    fn climbs(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        let mountain = self.mountains[pos];
+        self.mountains
+            .neighbours4(pos)
+            .filter(move |(_, neighbor)| mountain.can_reach(neighbor))
+            .map(|(neighbor, _)| neighbor)
+    }
+
+    /// The fewest steps from any of `starts` to the summit, `None` if it can't be reached.
+    fn shortest_path_to_summit(&self, starts: impl IntoIterator<Item = Pos>) -> Option<u32> {
+        search::bfs(starts, |&pos| self.climbs(pos))
+            .distance(&self.end_at)
+            .map(|distance| distance as u32)
+    }
+}
+
+/// Reports a map whose summit can't be reached, pointing at the summit.
+fn unreachable(input: &str) -> ParseError {
+    let summit = input
+        .find('E')
+        .map_or(&input[input.len()..], |i| &input[i..=i]);
+    ParseError::new(summit, "the summit can't be reached").locate(input)
+}
+
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    let map: MountainMap = input.parse()?;
+    map.shortest_path_to_summit([map.start_at])
+        .ok_or_else(|| unreachable(input))
+}
+
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
+    let map: MountainMap = input.parse()?;
+
+    // start from all the lowest mountains at once, the search finds the closest one.
+    let lowest = map
+        .mountains
+        .iter()
+        .filter(|(_, mountain)| mountain.height == 0)
+        .map(|(pos, _)| pos);
+    map.shortest_path_to_summit(lowest)
+        .ok_or_else(|| unreachable(input))
+}
+
+crate::solution!(Day12, 2022, 12, "Hill Climbing Algorithm");
@@ -0,0 +1,185 @@
+// I'm stuck because my code does exactly what the challenge description says and the example works, but my final answer is apparantly wrong ¯\_(ツ)_/¯
+// I don't have much free time left to figure this out, so I'm leaving it as is for now.
+// A nice solution which solves this problem in an idiomatic way is: https://www.ericburden.work/blog/2022/12/13/advent-of-code-2022-day-13/
+
+use crate::{helpers::scan, parse::ParseError};
+
+#[derive(Debug, PartialEq, Clone)]
+enum Value {
+    Integer(u32),
+    Array(Vec<Value>),
+}
+
+impl Value {
+    /// Unpacks the enum value to type `Vec<Value>`.
+    /// If the `Value` is an integer it will be converted.
+    fn unpack_array(&self) -> Vec<Value> {
+        match self {
+            Value::Integer(v) => vec![Value::Integer(*v)],
+            Value::Array(v) => v.to_vec(),
+        }
+    }
+}
+
+/// Finds an array string within a longer nested array string notation.
+///
+/// Example:
+/// ```text
+/// let input = "[[1, 2, 3]]";
+/// let sub = find_sub_array(1, input);
+/// println!("{:?}", sub); // [1, 2, 3]
+/// ```
+fn find_sub_array(index: usize, s: &str) -> &str {
+    let mut brackets_count = 0;
+    let mut end = s.len();
+
+    for (i, ch) in s[index..].char_indices() {
+        match ch {
+            '[' => brackets_count += 1,
+            ']' => brackets_count -= 1,
+            _ => {}
+        };
+
+        if brackets_count == 0 {
+            end = index + i + ch.len_utf8();
+            break;
+        }
+    }
+
+    &s[index..end]
+}
+
+fn parse(input: &str) -> Result<Vec<Value>, ParseError> {
+    let mut result: Vec<Value> = Vec::new();
+    let mut skip = 0;
+
+    for (i, ch) in input.char_indices() {
+        if i <= skip {
+            continue;
+        }
+
+        match ch {
+            '[' => {
+                let sub = find_sub_array(i, input);
+                result.push(Value::Array(parse(sub)?));
+                skip = i + sub.len();
+            }
+            ']' | ',' | ' ' => {}
+            _ => {
+                if let Some(digit) = ch.to_digit(10) {
+                    result.push(Value::Integer(digit));
+                } else {
+                    return Err(ParseError::new(
+                        &input[i..i + ch.len_utf8()],
+                        "expected a digit or a list",
+                    ));
+                }
+            }
+        };
+    }
+
+    Ok(result)
+}
+
+/// Compare two pairs and return whether they are in the right order.
+fn compare(left: &[Value], right: &[Value], check_if_right_ran_out: bool) -> bool {
+    let mut right_iter = right.iter();
+
+    for l in left.iter() {
+        if let Some(r) = right_iter.next() {
+            match (l, r) {
+                (Value::Integer(l), Value::Integer(r)) => {
+                    if r < l {
+                        return false;
+                    }
+                }
+                (Value::Array(l), Value::Array(r)) => {
+                    if !compare(l, r, true) {
+                        return false;
+                    }
+                }
+                (l, r) => {
+                    if !compare(&l.unpack_array(), &r.unpack_array(), false) {
+                        return false;
+                    }
+                }
+            };
+        } else {
+            // right side ran out of items
+            return !check_if_right_ran_out;
+        }
+    }
+
+    // left side ran out of items
+    true
+}
+
+fn parse_pair(pair: &str) -> Result<(Vec<Value>, Vec<Value>), ParseError> {
+    let (left, right) = pair
+        .split_once('\n')
+        .ok_or_else(|| ParseError::new(pair, "expected a pair of packets on two lines"))?;
+    Ok((parse(left.trim())?, parse(right.trim())?))
+}
+
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    let mut result: u32 = 0;
+
+    for (i, pair) in scan::blocks(input).enumerate() {
+        let (left, right) = parse_pair(pair).map_err(|e| e.locate(input))?;
+
+        if compare(&left, &right, true) {
+            // println!("{}",i + 1);
+            result += (i + 1) as u32;
+        }
+    }
+
+    Ok(result)
+}
+
+pub fn part_two(_input: &str) -> Option<u32> {
+    None
+}
+
+crate::solution!(Day13, 2022, 13, "Distress Signal");
+
+#[cfg(test)]
+mod tests {
+    use std::vec;
+
+    use super::*;
+
+    #[test]
+    fn test_find_sub_array() {
+        assert_eq!(find_sub_array(0, ""), "");
+        assert_eq!(find_sub_array(0, "[]"), "[]");
+        assert_eq!(find_sub_array(1, "[[]]"), "[]");
+        assert_eq!(find_sub_array(1, "[[[]]]"), "[[]]");
+        assert_eq!(find_sub_array(1, "[[1, 2, 3]]"), "[1, 2, 3]");
+        assert_eq!(
+            find_sub_array(1, "[[1, [2, [3, 4]], 5]]"),
+            "[1, [2, [3, 4]], 5]"
+        );
+        assert_eq!(find_sub_array(5, "[[1, [2, [3, 4]], 5]]"), "[2, [3, 4]]");
+    }
+
+    #[test]
+    fn test_parse() {
+        let result = parse("[1, [2, [3, 4]], 5]").unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0], Value::Integer(1));
+        assert_eq!(
+            result[1],
+            Value::Array(vec![
+                Value::Integer(2),
+                Value::Array(vec![Value::Integer(3), Value::Integer(4)])
+            ])
+        );
+        assert_eq!(result[2], Value::Integer(5));
+
+        let input = "[1,1]\n[1,[x]]";
+        assert_eq!(
+            part_one(input).unwrap_err().to_string(),
+            "expected a digit or a list, found \"x\" at line 2, column 5"
+        );
+    }
+}
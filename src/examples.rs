@@ -0,0 +1,232 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs,
+    io::{self, ErrorKind},
+    path::PathBuf,
+};
+
+use crate::{
+    days, manifest, read_optional, solve_part,
+    verify::{self, RecordedAnswers, Verdict},
+    year_path,
+};
+
+/// An example input of a day: `src/examples/<year>/NN.txt` or a named variant `src/examples/<year>/NN_<name>.txt`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Example {
+    pub year: i16,
+    pub day: u8,
+    /// `None` for the default example.
+    pub name: Option<String>,
+}
+
+impl Example {
+    pub fn path(&self) -> PathBuf {
+        year_path("examples", self.year).join(format!("{}.txt", self))
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        fs::read_to_string(self.path())
+    }
+}
+
+impl Display for Example {
+    /// Writes the file stem, e.g. `08` or `08_top_bottom`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "{:02}_{}", self.day, name),
+            None => write!(f, "{:02}", self.day),
+        }
+    }
+}
+
+/// Reads the example `src/examples/<year>/NN_<name>.txt` of `day` of `year`.
+pub fn read_example(year: i16, day: u8, name: &str) -> String {
+    let example = Example {
+        year,
+        day,
+        name: Some(name.into()),
+    };
+    example.read().unwrap_or_else(|e| {
+        panic!(
+            "could not open example file \"{}\": {}",
+            example.path().display(),
+            e
+        )
+    })
+}
+
+/// Lists the examples of `day` of `year` found in `src/examples/<year>`, the default example first.
+pub fn discover(year: i16, day: u8) -> io::Result<Vec<Example>> {
+    let prefix = format!("{:02}", day);
+    let mut examples = Vec::new();
+
+    let entries = match fs::read_dir(year_path("examples", year)) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(examples),
+        Err(e) => return Err(e),
+    };
+
+    for entry in entries {
+        let file_name = entry?.file_name();
+        let stem = match file_name.to_str().and_then(|f| f.strip_suffix(".txt")) {
+            Some(stem) => stem,
+            None => continue,
+        };
+
+        if stem == prefix {
+            examples.push(Example {
+                year,
+                day,
+                name: None,
+            });
+        } else if let Some(name) = stem.strip_prefix(&format!("{}_", prefix)) {
+            if !name.is_empty() {
+                examples.push(Example {
+                    year,
+                    day,
+                    name: Some(name.into()),
+                });
+            }
+        }
+    }
+
+    examples.sort();
+    Ok(examples)
+}
+
+/// Expected answers of the examples of a day, stored in `src/examples/<year>/NN.toml`.
+///
+/// Keys before the first section belong to the default example,
+/// a `[name]` section holds the answers of `NN_<name>.txt`:
+/// ```toml
+/// part_one = 21
+/// part_two = 8
+///
+/// [top_bottom]
+/// part_one = 23
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExpectedAnswers(pub BTreeMap<Option<String>, RecordedAnswers>);
+
+impl ExpectedAnswers {
+    pub fn get(&self, example: &Example) -> Option<&RecordedAnswers> {
+        self.0.get(&example.name)
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let mut expected = ExpectedAnswers::default();
+
+        for section in manifest::parse(contents)? {
+            for entry in section.entries {
+                let answer = verify::parse_entry(&entry)?;
+                expected
+                    .0
+                    .entry(section.name.map(str::to_owned))
+                    .or_default()
+                    .set(entry.part, answer);
+            }
+        }
+
+        Ok(expected)
+    }
+}
+
+/// Loads the expected example answers of `day` of `year`. A missing file means there are none.
+pub fn load_expected(year: i16, day: u8) -> io::Result<ExpectedAnswers> {
+    let path = year_path("examples", year).join(format!("{:02}.toml", day));
+
+    match read_optional(&path)? {
+        Some(contents) => ExpectedAnswers::parse(&contents).map_err(|e| {
+            io::Error::new(
+                ErrorKind::InvalidData,
+                format!("could not parse \"{}\": {}", path.display(), e),
+            )
+        }),
+        None => Ok(ExpectedAnswers::default()),
+    }
+}
+
+/// Runs `part` of `day` of `year` against an example and panics unless the answer matches `src/examples/<year>/NN.toml`.
+///
+/// This backs the tests that `build.rs` generates from the expected answers.
+pub fn assert_example(year: i16, day: u8, name: Option<&str>, part: u8) {
+    let example = Example {
+        year,
+        day,
+        name: name.map(Into::into),
+    };
+
+    let solution = days::get(year, day)
+        .unwrap_or_else(|| panic!("day {:02} of {} is not registered", day, year));
+    let expected = load_expected(year, day)
+        .unwrap()
+        .get(&example)
+        .and_then(|answers| answers.get(part).cloned())
+        .unwrap_or_else(|| panic!("{} has no expected answer for part {}", example, part));
+    let input = example.read().unwrap_or_else(|e| {
+        panic!(
+            "could not open example file \"{}\": {}",
+            example.path().display(),
+            e
+        )
+    });
+
+    let outcome = solve_part(solution, part, &input).value;
+    if let Verdict::Wrong { expected } = Verdict::check(&outcome, Some(&expected)) {
+        panic!(
+            "{} part {}: expected {}, got {}",
+            example, part, expected, outcome
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Answer;
+
+    #[test]
+    fn test_parse_expected() {
+        let expected =
+            ExpectedAnswers::parse("part_one = 21\n\n[top_bottom]\npart_one = 23\n").unwrap();
+
+        let default = Example {
+            year: 2022,
+            day: 8,
+            name: None,
+        };
+        let named = Example {
+            year: 2022,
+            day: 8,
+            name: Some("top_bottom".into()),
+        };
+        assert_eq!(
+            expected.get(&default).and_then(|a| a.get(1)),
+            Some(&Answer::Integer(21))
+        );
+        assert_eq!(
+            expected.get(&named).and_then(|a| a.get(1)),
+            Some(&Answer::Integer(23))
+        );
+        assert_eq!(expected.get(&named).and_then(|a| a.get(2)), None);
+
+        assert!(ExpectedAnswers::parse("[]\npart_one = 1").is_err());
+        assert!(ExpectedAnswers::parse("[name\npart_one = 1").is_err());
+    }
+
+    #[test]
+    fn test_discover() {
+        let examples = discover(2022, 8).unwrap();
+        assert_eq!(
+            examples.iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            vec!["08", "08_top_bottom"]
+        );
+        assert_eq!(discover(2015, 8).unwrap(), vec![]);
+    }
+}
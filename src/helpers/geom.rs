@@ -0,0 +1,416 @@
+//! Points and directions on a grid, in screen coordinates: `x` grows to the right and `y` grows downwards,
+//! so [`Direction4::Up`] is a step of `y - 1`.
+use std::{
+    cmp::Ordering,
+    fmt::Debug,
+    hash::Hash,
+    ops::{Add, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+use derive_more::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::ParseError;
+
+/// An integer type that can be used as the coordinates of a point.
+pub trait Int:
+    Copy + Debug + Default + Ord + Hash + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// `-1`, `0` or `1` depending on the sign of `self`. Unsigned types never return `-1`.
+    fn sign(self) -> Self {
+        match self.cmp(&Self::ZERO) {
+            Ordering::Less => Self::ZERO - Self::ONE,
+            Ordering::Equal => Self::ZERO,
+            Ordering::Greater => Self::ONE,
+        }
+    }
+
+    /// The distance between `self` and `other`, without overflowing for unsigned types.
+    fn distance(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+}
+
+macro_rules! impl_int {
+    ($($int:ty),*) => {
+        $(
+            impl Int for $int {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+            }
+        )*
+    };
+}
+
+impl_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// A point or an offset on a 2D grid.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Add,
+    AddAssign,
+    Sub,
+    SubAssign,
+    Mul,
+    Neg,
+)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Int> Point2<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Point2 { x, y }
+    }
+
+    /// Sum of the distances along each axis, the number of orthogonal steps from `self` to `other`.
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y)
+    }
+
+    /// Largest of the distances along each axis, the number of king's moves from `self` to `other`.
+    pub fn chebyshev(self, other: Self) -> T {
+        self.x.distance(other.x).max(self.y.distance(other.y))
+    }
+
+    /// The sign of each coordinate, i.e. a single step of at most 1 along each axis in the direction of `self`.
+    pub fn signum(self) -> Self {
+        Point2::new(self.x.sign(), self.y.sign())
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+/// A point or an offset in 3D space.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Add,
+    AddAssign,
+    Sub,
+    SubAssign,
+    Mul,
+    Neg,
+)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Int> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+
+    /// Sum of the distances along each axis.
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y) + self.z.distance(other.z)
+    }
+
+    /// Largest of the distances along each axis.
+    pub fn chebyshev(self, other: Self) -> T {
+        self.x
+            .distance(other.x)
+            .max(self.y.distance(other.y))
+            .max(self.z.distance(other.z))
+    }
+
+    /// The sign of each coordinate.
+    pub fn signum(self) -> Self {
+        Point3::new(self.x.sign(), self.y.sign(), self.z.sign())
+    }
+}
+
+/// Converts a step of `(dx, dy)` of `-1`, `0` or `1` to a point of a signed type.
+fn offset<T: Int + Neg<Output = T>>((dx, dy): (i8, i8)) -> Point2<T> {
+    let unit = |d: i8| match d {
+        -1 => -T::ONE,
+        0 => T::ZERO,
+        _ => T::ONE,
+    };
+    Point2::new(unit(dx), unit(dy))
+}
+
+/// One of the 4 orthogonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    /// All directions, clockwise from up.
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    /// Rotates by 90° clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// Rotates by 90° counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The offset of a single step in this direction.
+    pub fn offset<T: Int + Neg<Output = T>>(self) -> Point2<T> {
+        Direction8::from(self).offset()
+    }
+}
+
+impl FromStr for Direction4 {
+    type Err = ParseError;
+
+    /// Parses `U`, `R`, `D` and `L` or the compass directions `N`, `E`, `S` and `W`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" | "N" => Ok(Direction4::Up),
+            "R" | "E" => Ok(Direction4::Right),
+            "D" | "S" => Ok(Direction4::Down),
+            "L" | "W" => Ok(Direction4::Left),
+            _ => Err(ParseError::new(
+                s,
+                "expected a direction (U, R, D, L, N, E, S or W)",
+            )),
+        }
+    }
+}
+
+/// One of the 4 orthogonal or 4 diagonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// All directions, clockwise from up.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Rotates by 45° clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Rotates by 45° counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// The offset of a single step in this direction.
+    pub fn offset<T: Int + Neg<Output = T>>(self) -> Point2<T> {
+        offset(match self {
+            Direction8::Up => (0, -1),
+            Direction8::UpRight => (1, -1),
+            Direction8::Right => (1, 0),
+            Direction8::DownRight => (1, 1),
+            Direction8::Down => (0, 1),
+            Direction8::DownLeft => (-1, 1),
+            Direction8::Left => (-1, 0),
+            Direction8::UpLeft => (-1, -1),
+        })
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+impl FromStr for Direction8 {
+    type Err = ParseError;
+
+    /// Parses `U`, `R`, `D` and `L` or the compass directions `N`, `NE`, `E`, `SE`, `S`, `SW`, `W` and `NW`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NE" => Ok(Direction8::UpRight),
+            "SE" => Ok(Direction8::DownRight),
+            "SW" => Ok(Direction8::DownLeft),
+            "NW" => Ok(Direction8::UpLeft),
+            _ => s.parse::<Direction4>().map(Direction8::from).map_err(|_| {
+                ParseError::new(
+                    s,
+                    "expected a direction (U, R, D, L, N, NE, E, SE, S, SW, W or NW)",
+                )
+            }),
+        }
+    }
+}
+
+/// The smallest rectangle containing a set of points, with inclusive bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundingBox<T> {
+    pub min: Point2<T>,
+    pub max: Point2<T>,
+}
+
+impl<T: Int> BoundingBox<T> {
+    /// The bounding box of `points`, `None` if there are none.
+    pub fn of(points: impl IntoIterator<Item = Point2<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let mut bounds = BoundingBox {
+            min: first,
+            max: first,
+        };
+        points.for_each(|point| bounds.extend(point));
+        Some(bounds)
+    }
+
+    /// Grows the box to contain `point`.
+    pub fn extend(&mut self, point: Point2<T>) {
+        self.min = Point2::new(self.min.x.min(point.x), self.min.y.min(point.y));
+        self.max = Point2::new(self.max.x.max(point.x), self.max.y.max(point.y));
+    }
+
+    pub fn contains(&self, point: Point2<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    /// Number of columns in the box.
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::ONE
+    }
+
+    /// Number of rows in the box.
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::ONE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point2() {
+        let (a, b) = (Point2::new(1, -2), Point2::new(-3, 4));
+        assert_eq!(a + b, Point2::new(-2, 2));
+        assert_eq!(a - b, Point2::new(4, -6));
+        assert_eq!(a * 3, Point2::new(3, -6));
+        assert_eq!(-a, Point2::new(-1, 2));
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!((b - a).signum(), Point2::new(-1, 1));
+
+        let (c, d) = (Point2::new(2_usize, 7), Point2::new(5_usize, 3));
+        assert_eq!(c.manhattan(d), 7);
+        assert_eq!(c.chebyshev(d), 4);
+        assert_eq!(Point2::from((2_usize, 7)), c);
+    }
+
+    #[test]
+    fn test_point3() {
+        let (a, b) = (Point3::new(1_i64, 2, 3), Point3::new(-1, 5, 3));
+        assert_eq!(a + b, Point3::new(0, 7, 6));
+        assert_eq!(a.manhattan(b), 5);
+        assert_eq!(a.chebyshev(b), 3);
+        assert_eq!((b - a).signum(), Point3::new(-1, 1, 0));
+    }
+
+    #[test]
+    fn test_direction4() {
+        assert_eq!(Direction4::Up.turn_right(), Direction4::Right);
+        assert_eq!(Direction4::Up.turn_left(), Direction4::Left);
+        assert_eq!(Direction4::Left.reverse(), Direction4::Right);
+        assert_eq!(Direction4::Up.offset::<i32>(), Point2::new(0, -1));
+        assert_eq!(Direction4::Left.offset::<i8>(), Point2::new(-1, 0));
+
+        assert_eq!("U".parse(), Ok(Direction4::Up));
+        assert_eq!("W".parse(), Ok(Direction4::Left));
+        assert!("X".parse::<Direction4>().is_err());
+    }
+
+    #[test]
+    fn test_direction8() {
+        assert_eq!(Direction8::Up.turn_right(), Direction8::UpRight);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownLeft.reverse(), Direction8::UpRight);
+        assert_eq!(Direction8::DownLeft.offset::<i32>(), Point2::new(-1, 1));
+        assert_eq!(Direction8::from(Direction4::Down), Direction8::Down);
+
+        assert_eq!("NE".parse(), Ok(Direction8::UpRight));
+        assert_eq!("R".parse(), Ok(Direction8::Right));
+        assert_eq!(
+            "Q".parse::<Direction8>().unwrap_err().to_string(),
+            "expected a direction (U, R, D, L, N, NE, E, SE, S, SW, W or NW), found \"Q\""
+        );
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let points = [Point2::new(2, -1), Point2::new(-3, 4), Point2::new(0, 0)];
+        let bounds = BoundingBox::of(points).unwrap();
+        assert_eq!(bounds.min, Point2::new(-3, -1));
+        assert_eq!(bounds.max, Point2::new(2, 4));
+        assert_eq!((bounds.width(), bounds.height()), (6, 6));
+        assert!(bounds.contains(Point2::new(0, 3)));
+        assert!(!bounds.contains(Point2::new(3, 0)));
+        assert_eq!(BoundingBox::<i32>::of([]), None);
+    }
+}
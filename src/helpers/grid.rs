@@ -0,0 +1,284 @@
+//! A rectangular grid of cells, e.g. a map read from the characters of the puzzle input.
+use std::ops::{Index, IndexMut};
+
+use super::geom::{Direction4, Direction8, Point2};
+use crate::ParseError;
+
+/// A position in a [`Grid`], where `(0, 0)` is the top left cell and `y` grows downwards.
+pub type Pos = Point2<usize>;
+
+/// A rectangular grid of cells, stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid of `width` x `height` cells, calling `func` with the position of every cell.
+    pub fn from_fn(width: usize, height: usize, mut func: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point2::new(x, y)))
+            .map(&mut func)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a character map, one row per line, mapping every character to a cell with `func`.
+    ///
+    /// Fails on characters `func` returns `None` for and on rows that are not as wide as the first row.
+    pub fn parse(input: &str, mut func: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for line in input.lines() {
+            let before = cells.len();
+            for (i, c) in line.char_indices() {
+                let cell = func(c).ok_or_else(|| {
+                    ParseError::new(&line[i..i + c.len_utf8()], "unexpected character")
+                        .locate(input)
+                })?;
+                cells.push(cell);
+            }
+
+            let row_width = cells.len() - before;
+            match width {
+                Some(width) if width != row_width => {
+                    let message = format!("expected a row of {} cells like the first row", width);
+                    return Err(ParseError::new(line, message).locate(input));
+                }
+                _ => width = Some(row_width),
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    /// Returns the cell at `pos`, `None` if it is outside the grid.
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.y * self.width + pos.x])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.y * self.width + pos.x])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the position `offset` away from `pos`, `None` if it is outside the grid.
+    pub fn step(&self, pos: Pos, offset: Point2<isize>) -> Option<Pos> {
+        let pos = Point2::new(
+            pos.x.checked_add_signed(offset.x)?,
+            pos.y.checked_add_signed(offset.y)?,
+        );
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Iterates over all cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| (Point2::new(i % width, i / width), cell))
+    }
+
+    /// Returns the position of the first cell, row by row, that matches `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// Iterates over the cells `offsets` away from `pos`, skipping those outside the grid.
+    pub fn neighbours(
+        &self,
+        pos: Pos,
+        offsets: impl IntoIterator<Item = Point2<isize>>,
+    ) -> impl Iterator<Item = (Pos, &T)> {
+        offsets
+            .into_iter()
+            .filter_map(move |offset| self.step(pos, offset))
+            .map(move |pos| (pos, &self[pos]))
+    }
+
+    /// Iterates over the orthogonal neighbours of `pos` that are inside the grid, clockwise from up.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        self.neighbours(pos, Direction4::ALL.map(Direction4::offset))
+    }
+
+    /// Iterates over the orthogonal and diagonal neighbours of `pos` that are inside the grid, clockwise from up.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> {
+        self.neighbours(pos, Direction8::ALL.map(Direction8::offset))
+    }
+
+    /// Iterates over the cells from `pos` in `direction` until the edge of the grid, excluding `pos` itself.
+    pub fn ray(
+        &self,
+        pos: Pos,
+        direction: impl Into<Direction8>,
+    ) -> impl Iterator<Item = (Pos, &T)> {
+        let offset = direction.into().offset();
+        let mut current = pos;
+        std::iter::from_fn(move || {
+            current = self.step(current, offset)?;
+            Some((current, &self[current]))
+        })
+    }
+
+    /// Returns row `y`, from left to right.
+    ///
+    /// Panics if `y` is outside the grid.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {} is outside the grid", y);
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Iterates over column `x`, from top to bottom.
+    ///
+    /// Panics if `x` is outside the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside the grid", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Iterates over the rows, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a chunk size of zero.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Creates a grid of the same size with `func` applied to every cell.
+    pub fn map<U>(&self, func: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(func).collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    /// Panics if `pos` is outside the grid, use [`Grid::get`] to check.
+    fn index(&self, pos: Pos) -> &Self::Output {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!(
+                "{:?} is outside the grid of {}x{}",
+                pos, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside the grid of {}x{}", pos, width, height),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(x: usize, y: usize) -> Pos {
+        Point2::new(x, y)
+    }
+
+    fn digits() -> Grid<u32> {
+        Grid::parse("123\n456\n789\n", |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid[p(2, 0)], 3);
+        assert_eq!(grid.get(p(0, 2)), Some(&7));
+        assert_eq!(grid.get(p(3, 0)), None);
+        assert_eq!(
+            grid,
+            Grid::from_fn(3, 3, |pos| (pos.y * 3 + pos.x + 1) as u32)
+        );
+
+        let error = Grid::parse("12\n3x", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unexpected character, found \"x\" at line 2, column 2"
+        );
+
+        let error = Grid::parse("12\n345", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(error.position(), Some((2, 1)));
+
+        let empty = Grid::parse("", |c| c.to_digit(10)).unwrap();
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+        assert_eq!(empty.rows().count(), 0);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits();
+        let values =
+            |cells: Vec<(Pos, &u32)>| cells.into_iter().map(|(_, v)| *v).collect::<Vec<_>>();
+
+        assert_eq!(
+            values(grid.neighbours4(p(1, 1)).collect()),
+            vec![2, 6, 8, 4]
+        );
+        assert_eq!(values(grid.neighbours4(p(0, 0)).collect()), vec![2, 4]);
+        assert_eq!(
+            values(grid.neighbours8(p(1, 1)).collect()),
+            vec![2, 3, 6, 9, 8, 7, 4, 1]
+        );
+        assert_eq!(values(grid.neighbours8(p(2, 2)).collect()), vec![6, 8, 5]);
+    }
+
+    #[test]
+    fn test_lines() {
+        let mut grid = digits();
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![3, 6, 9]);
+        assert_eq!(grid.rows().count(), 3);
+
+        let ray: Vec<_> = grid.ray(p(0, 2), Direction8::UpRight).collect();
+        assert_eq!(ray, vec![(p(1, 1), &5), (p(2, 0), &3)]);
+        assert_eq!(grid.ray(p(1, 1), Direction8::Left).count(), 1);
+        assert_eq!(grid.ray(p(0, 1), Direction4::Left).count(), 0);
+
+        grid[p(1, 1)] = 0;
+        assert_eq!(grid.position(|&v| v == 0), Some(p(1, 1)));
+        assert_eq!(grid.map(|v| v % 2).row(0), &[1, 0, 1]);
+    }
+}
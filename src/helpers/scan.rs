@@ -0,0 +1,264 @@
+//! Small helpers to read the usual puzzle input formats, reporting malformed input as a [`ParseError`].
+//!
+//! Errors point at slices of the text that was passed in, call [`ParseError::locate`] with the whole input
+//! to add the line and column.
+use std::{fmt::Display, str::FromStr};
+
+use crate::{parse, ParseError};
+
+/// Extracts all integers from `line`, e.g. `[2, -3]` from `"x=2, y=-3"`.
+///
+/// A `-` directly in front of a number is its sign, unless it follows a letter or digit:
+/// `"2-4"` is a range of two numbers, `2` and `4`.
+pub fn ints<T>(line: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let bytes = line.as_bytes();
+    let mut numbers = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        let mut start = i;
+        if start > 0
+            && bytes[start - 1] == b'-'
+            && (start < 2 || !bytes[start - 2].is_ascii_alphanumeric())
+        {
+            start -= 1;
+        }
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        numbers.push(parse::number(&line[start..i])?);
+    }
+
+    Ok(numbers)
+}
+
+/// Splits `input` into its blocks of lines separated by blank lines, without the line breaks around them.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .split("\n\n")
+        .map(|block| block.trim_matches('\n'))
+        .filter(|block| !block.is_empty())
+}
+
+/// A value that can be read from the text matched by a `{}` placeholder of a [`pattern`].
+///
+/// Implement it for your own types to use them in patterns, e.g. by calling their `FromStr` implementation.
+pub trait Capture<'a>: Sized {
+    fn capture(text: &'a str) -> Result<Self, ParseError>;
+}
+
+impl<'a> Capture<'a> for &'a str {
+    fn capture(text: &'a str) -> Result<Self, ParseError> {
+        Ok(text)
+    }
+}
+
+impl<'a> Capture<'a> for String {
+    fn capture(text: &'a str) -> Result<Self, ParseError> {
+        Ok(text.to_owned())
+    }
+}
+
+impl<'a> Capture<'a> for char {
+    fn capture(text: &'a str) -> Result<Self, ParseError> {
+        let mut chars = text.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(ParseError::new(text, "expected a single character")),
+        }
+    }
+}
+
+macro_rules! impl_capture_number {
+    ($($number:ty),*) => {
+        $(
+            impl<'a> Capture<'a> for $number {
+                fn capture(text: &'a str) -> Result<Self, ParseError> {
+                    parse::number(text)
+                }
+            }
+        )*
+    };
+}
+
+impl_capture_number!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// The values of all placeholders of a [`pattern`]: a single [`Capture`] or a tuple of them.
+pub trait Captures<'a>: Sized {
+    /// Number of placeholders the pattern must have.
+    const COUNT: usize;
+
+    fn from_captures(captures: &[&'a str]) -> Result<Self, ParseError>;
+}
+
+impl<'a, T: Capture<'a>> Captures<'a> for T {
+    const COUNT: usize = 1;
+
+    fn from_captures(captures: &[&'a str]) -> Result<Self, ParseError> {
+        T::capture(captures[0])
+    }
+}
+
+macro_rules! impl_captures_tuple {
+    ($count:expr; $($name:ident),+) => {
+        impl<'a, $($name: Capture<'a>),+> Captures<'a> for ($($name,)+) {
+            const COUNT: usize = $count;
+
+            fn from_captures(captures: &[&'a str]) -> Result<Self, ParseError> {
+                let mut captures = captures.iter();
+                Ok(($($name::capture(captures.next().unwrap())?,)+))
+            }
+        }
+    };
+}
+
+impl_captures_tuple!(2; A, B);
+impl_captures_tuple!(3; A, B, C);
+impl_captures_tuple!(4; A, B, C, D);
+impl_captures_tuple!(5; A, B, C, D, E);
+impl_captures_tuple!(6; A, B, C, D, E, F);
+
+/// Matches `text` against `pattern`, returning the text matched by each `{}` placeholder.
+///
+/// Everything else in `pattern` must match literally, including whitespace and line breaks.
+/// A placeholder matches everything up to the next occurrence of the text after it.
+///
+/// Panics if two placeholders follow each other without text in between, as it would be ambiguous.
+pub fn captures<'a>(text: &'a str, pattern: &str) -> Result<Vec<&'a str>, ParseError> {
+    let expected = || format!("expected {:?}", pattern);
+    let mut literals = pattern.split("{}");
+    let mut rest = text;
+    let mut captures = Vec::new();
+
+    let prefix = literals.next().unwrap_or("");
+    rest = rest
+        .strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(rest, expected()))?;
+
+    let literals: Vec<&str> = literals.collect();
+    for (i, literal) in literals.iter().enumerate() {
+        let is_last = i + 1 == literals.len();
+        let (capture, after) = if is_last {
+            // the last placeholder takes everything up to the suffix.
+            let capture = rest
+                .strip_suffix(literal)
+                .ok_or_else(|| ParseError::new(&rest[rest.len()..], expected()))?;
+            (capture, "")
+        } else {
+            assert!(
+                !literal.is_empty(),
+                "placeholders in {:?} must be separated by text",
+                pattern
+            );
+            let end = rest
+                .find(literal)
+                .ok_or_else(|| ParseError::new(rest, expected()))?;
+            (&rest[..end], &rest[end + literal.len()..])
+        };
+        captures.push(capture);
+        rest = after;
+    }
+
+    if literals.is_empty() && !rest.is_empty() {
+        return Err(ParseError::new(rest, expected()));
+    }
+
+    Ok(captures)
+}
+
+/// Matches `text` against `pattern` like [`captures`] and reads the placeholders as `T`,
+/// e.g. `let (count, from, to): (usize, usize, usize) = pattern(line, "move {} from {} to {}")?;`.
+///
+/// Panics if `pattern` does not have as many placeholders as `T` has values.
+pub fn pattern<'a, T: Captures<'a>>(text: &'a str, pattern: &str) -> Result<T, ParseError> {
+    let captures = captures(text, pattern)?;
+    assert_eq!(
+        captures.len(),
+        T::COUNT,
+        "{:?} has {} placeholder(s), expected {}",
+        pattern,
+        captures.len(),
+        T::COUNT
+    );
+    T::from_captures(&captures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(
+            ints::<i32>("Sensor at x=2, y=-18: beacon at x=-2, y=15"),
+            Ok(vec![2, -18, -2, 15])
+        );
+        assert_eq!(ints::<u32>("2-4,6-8"), Ok(vec![2, 4, 6, 8]));
+        assert_eq!(ints::<i64>("addx -5"), Ok(vec![-5]));
+        assert_eq!(ints::<i64>("-7 - 3"), Ok(vec![-7, 3]));
+        assert_eq!(ints::<i64>("no numbers"), Ok(vec![]));
+
+        let error = ints::<u8>("1, 300").unwrap_err();
+        assert_eq!(error.snippet(), "300");
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "a\nb\n\nc\n\n\nd\n";
+        assert_eq!(blocks(input).collect::<Vec<_>>(), vec!["a\nb", "c", "d"]);
+        assert_eq!(blocks("").count(), 0);
+    }
+
+    #[test]
+    fn test_pattern() {
+        let step: (usize, usize, usize) =
+            pattern("move 1 from 2 to 3", "move {} from {} to {}").unwrap();
+        assert_eq!(step, (1, 2, 3));
+
+        let ranges: (u32, u32, u32, u32) = pattern("2-4,6-8", "{}-{},{}-{}").unwrap();
+        assert_eq!(ranges, (2, 4, 6, 8));
+
+        let amount: i32 = pattern("addx -5", "addx {}").unwrap();
+        assert_eq!(amount, -5);
+
+        let (name, c): (&str, char) = pattern("Monkey 0: x", "Monkey {}: {}").unwrap();
+        assert_eq!((name, c), ("0", 'x'));
+
+        assert_eq!(captures("noop", "noop"), Ok(vec![]));
+        assert!(captures("noop!", "noop").is_err());
+    }
+
+    #[test]
+    fn test_pattern_errors() {
+        let input = "move 1 from 2 to 3\nmove 1 frm 2 to 3";
+        let line = input.lines().nth(1).unwrap();
+
+        let error = pattern::<(u8, u8, u8)>(line, "move {} from {} to {}").unwrap_err();
+        assert_eq!(
+            error.locate(input).to_string(),
+            "expected \"move {} from {} to {}\", found \"1 frm 2 to 3\" at line 2, column 6"
+        );
+
+        let error =
+            pattern::<(u8, u8, u8)>("move x from 2 to 3", "move {} from {} to {}").unwrap_err();
+        assert_eq!(error.snippet(), "x");
+
+        let error = pattern::<u8>("Test: divisible", "Test: divisible by {}").unwrap_err();
+        assert_eq!(error.snippet(), "Test: divisible");
+    }
+
+    #[test]
+    #[should_panic(expected = "has 2 placeholder(s), expected 3")]
+    fn test_pattern_count() {
+        let _ = pattern::<(u8, u8, u8)>("1-2", "{}-{}");
+    }
+}
@@ -0,0 +1,291 @@
+//! Shortest paths over any graph, described by a closure that lists the neighbours of a node.
+//!
+//! Example, the fewest steps between two cells of a [`Grid`](super::Grid) of walls:
+//! `search::bfs([start], |&pos| grid.neighbours4(pos).filter(|(_, wall)| !**wall).map(|(pos, _)| pos)).distance(&end)`.
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The distances of the nodes reached by a search and how each was reached.
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    distances: HashMap<N, C>,
+    predecessors: HashMap<N, N>,
+}
+
+impl<N: Eq + Hash + Clone, C: Copy> Paths<N, C> {
+    fn new() -> Self {
+        Paths {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    /// Distance from the closest source to `node`, `None` if it was not reached.
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    /// Distances of all reached nodes, including the sources.
+    pub fn distances(&self) -> &HashMap<N, C> {
+        &self.distances
+    }
+
+    /// The node `node` was reached from on a shortest path, `None` for sources and nodes that were not reached.
+    pub fn predecessor(&self, node: &N) -> Option<&N> {
+        self.predecessors.get(node)
+    }
+
+    /// A shortest path from one of the sources to `node`, both included. `None` if `node` was not reached.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.predecessor(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// An entry of the open set, ordered so that a `BinaryHeap` pops the lowest `priority` first.
+struct State<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for State<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for State<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for State<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for State<N, C> {}
+
+/// Breadth-first search from all `sources` at once, where every step costs 1.
+///
+/// Visits every node that can be reached, `neighbours` is called once per reached node.
+pub fn bfs<N, I>(
+    sources: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> Paths<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+
+    for source in sources {
+        if !paths.distances.contains_key(&source) {
+            paths.distances.insert(source.clone(), 0);
+            queue.push_back(source);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let distance = paths.distances[&node];
+        for next in neighbours(&node) {
+            if !paths.distances.contains_key(&next) {
+                paths.distances.insert(next.clone(), distance + 1);
+                paths.predecessors.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    paths
+}
+
+/// Dijkstra's algorithm from all `sources` at once, where `neighbours` lists the nodes
+/// reachable from a node with the cost of each step. Costs must not be negative.
+///
+/// Visits every node that can be reached.
+pub fn dijkstra<N, C, I>(
+    sources: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> Paths<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new();
+    search(
+        &mut paths,
+        sources,
+        |_| false,
+        &mut neighbours,
+        |_| C::default(),
+    );
+    paths
+}
+
+/// A* search from all `sources` at once to the closest node matching `is_goal`.
+///
+/// `heuristic` estimates the remaining cost from a node to a goal and must never overestimate it,
+/// otherwise the returned path may not be the shortest. Returns the path from a source to the goal,
+/// both included, and its cost, `None` if no goal can be reached.
+pub fn astar<N, C, I>(
+    sources: impl IntoIterator<Item = N>,
+    is_goal: impl FnMut(&N) -> bool,
+    mut neighbours: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+) -> Option<(Vec<N>, C)>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new();
+    let goal = search(&mut paths, sources, is_goal, &mut neighbours, heuristic)?;
+    Some((paths.path_to(&goal)?, paths.distance(&goal)?))
+}
+
+/// Explores the graph cheapest first, ordered by cost plus `heuristic`, until a node matches `is_goal`.
+/// Returns the goal that was reached, `None` once every reachable node has been visited.
+fn search<N, C, I>(
+    paths: &mut Paths<N, C>,
+    sources: impl IntoIterator<Item = N>,
+    mut is_goal: impl FnMut(&N) -> bool,
+    neighbours: &mut impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+) -> Option<N>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut open = BinaryHeap::new();
+
+    for source in sources {
+        let cost = C::default();
+        paths.distances.insert(source.clone(), cost);
+        open.push(State {
+            priority: cost + heuristic(&source),
+            cost,
+            node: source,
+        });
+    }
+
+    while let Some(State { cost, node, .. }) = open.pop() {
+        // a cheaper way to this node was found after it was added to the open set.
+        if paths.distance(&node).is_some_and(|best| cost > best) {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some(node);
+        }
+
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            if paths.distance(&next).is_none_or(|best| next_cost < best) {
+                paths.distances.insert(next.clone(), next_cost);
+                paths.predecessors.insert(next.clone(), node.clone());
+                open.push(State {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A small weighted graph: a -1- b -1- c -1- d and a -5- d, e is isolated.
+    fn edges(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('d', 5)],
+            'b' => vec![('a', 1), ('c', 1)],
+            'c' => vec![('b', 1), ('d', 1)],
+            'd' => vec![('c', 1), ('a', 5)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let paths = bfs(['a'], |node| edges(node).into_iter().map(|(next, _)| next));
+        assert_eq!(paths.distance(&'d'), Some(1));
+        assert_eq!(paths.distance(&'c'), Some(2));
+        assert_eq!(paths.distance(&'e'), None);
+        assert_eq!(paths.path_to(&'c'), Some(vec!['a', 'b', 'c']));
+        assert_eq!(paths.path_to(&'a'), Some(vec!['a']));
+        assert_eq!(paths.path_to(&'e'), None);
+
+        let paths = bfs(['a', 'd'], |node| {
+            edges(node).into_iter().map(|(next, _)| next)
+        });
+        assert_eq!(paths.distance(&'c'), Some(1));
+        assert_eq!(paths.predecessor(&'c'), Some(&'d'));
+        assert_eq!(paths.distances().len(), 4);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let paths = dijkstra(['a'], edges);
+        assert_eq!(paths.distance(&'d'), Some(3));
+        assert_eq!(paths.path_to(&'d'), Some(vec!['a', 'b', 'c', 'd']));
+        assert_eq!(paths.distance(&'e'), None);
+
+        let paths = dijkstra(['a', 'e'], edges);
+        assert_eq!(paths.distance(&'e'), Some(0));
+        assert_eq!(paths.distance(&'c'), Some(2));
+    }
+
+    #[test]
+    fn test_astar() {
+        assert_eq!(
+            astar(['a'], |&node| node == 'd', edges, |_| 0),
+            Some((vec!['a', 'b', 'c', 'd'], 3))
+        );
+        assert_eq!(astar(['a'], |&node| node == 'e', edges, |_| 0), None);
+
+        // manhattan distance on an open grid, with a wall at x = 1 except at y = 3.
+        let goal = (2_i32, 0_i32);
+        let (path, cost) = astar(
+            [(0_i32, 0_i32)],
+            |&pos| pos == goal,
+            |&(x, y)| {
+                [(0, -1), (1, 0), (0, 1), (-1, 0)]
+                    .into_iter()
+                    .map(move |(dx, dy)| (x + dx, y + dy))
+                    .filter(|&(x, y)| {
+                        (0..3).contains(&x) && (0..4).contains(&y) && (x != 1 || y == 3)
+                    })
+                    .map(|pos| (pos, 1))
+            },
+            |&(x, y)| goal.0.abs_diff(x) + goal.1.abs_diff(y),
+        )
+        .unwrap();
+        assert_eq!(cost, 8);
+        assert_eq!(path.len(), 9);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&goal));
+    }
+}
@@ -0,0 +1,381 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    fmt::{self, Display},
+    fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+    process::Command,
+    time::Duration,
+};
+
+use crate::{
+    bench::{BenchReport, Stats},
+    project_path, read_optional,
+};
+
+/// Default location of the benchmark history in the project root.
+pub fn default_path() -> PathBuf {
+    project_path(".bench-history.csv")
+}
+
+const HEADER: &str = "commit,year,day,part,samples,min_ns,median_ns,mean_ns,stddev_ns";
+
+/// Benchmark statistics of a single day and part, recorded for a commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub commit: String,
+    pub year: i16,
+    pub day: u8,
+    pub part: u8,
+    pub stats: Stats,
+}
+
+impl Entry {
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields: Vec<&str> = line.split(',').collect();
+        if fields.len() != 9 {
+            return None;
+        }
+        let year = fields.remove(1).parse().ok()?;
+
+        let nanos = |i: usize| fields[i].parse().ok().map(Duration::from_nanos);
+
+        Some(Entry {
+            commit: fields[0].to_owned(),
+            year,
+            day: fields[1].parse().ok()?,
+            part: fields[2].parse().ok()?,
+            stats: Stats {
+                samples: fields[3].parse().ok()?,
+                min: nanos(4)?,
+                median: nanos(5)?,
+                mean: nanos(6)?,
+                stddev: nanos(7)?,
+            },
+        })
+    }
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{},{},{},{}",
+            self.commit,
+            self.year,
+            self.day,
+            self.part,
+            self.stats.samples,
+            self.stats.min.as_nanos(),
+            self.stats.median.as_nanos(),
+            self.stats.mean.as_nanos(),
+            self.stats.stddev.as_nanos()
+        )
+    }
+}
+
+/// Returns the short hash of the checked out git commit, suffixed with `-dirty` if the worktree has changes.
+/// Falls back to `unknown` if git is not available.
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(commit) => match git(&["status", "--porcelain", "--untracked-files=no"]) {
+            Some(status) if !status.is_empty() => format!("{}-dirty", commit),
+            _ => commit,
+        },
+        None => "unknown".into(),
+    }
+}
+
+/// Loads all entries from the history file at `path`, oldest first. A missing file is an empty history.
+pub fn load(path: &Path) -> io::Result<Vec<Entry>> {
+    let contents = match read_optional(path)? {
+        Some(contents) => contents,
+        None => return Ok(Vec::new()),
+    };
+
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty() && *line != HEADER)
+        .map(|(i, line)| {
+            Entry::parse(line).ok_or_else(|| {
+                io::Error::new(
+                    ErrorKind::InvalidData,
+                    format!("{}:{}: malformed history entry", path.display(), i + 1),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Stores the benchmarked parts of `reports` for `commit`, replacing earlier results of that commit for the same parts.
+/// Results of other parts are kept, so benchmarking a single day does not discard the rest of the commit.
+pub fn save(path: &Path, commit: &str, reports: &[BenchReport]) -> io::Result<()> {
+    let new_entries: Vec<Entry> = reports
+        .iter()
+        .filter_map(|report| {
+            report.stats.map(|stats| Entry {
+                commit: commit.to_owned(),
+                year: report.year,
+                day: report.day,
+                part: report.part,
+                stats,
+            })
+        })
+        .collect();
+
+    let mut entries: Vec<Entry> = load(path)?
+        .into_iter()
+        .filter(|entry| {
+            !new_entries.iter().any(|new| {
+                new.commit == entry.commit
+                    && new.year == entry.year
+                    && new.day == entry.day
+                    && new.part == entry.part
+            })
+        })
+        .collect();
+    entries.extend(new_entries);
+
+    let mut contents = String::from(HEADER);
+    contents.push('\n');
+    for entry in entries {
+        contents.push_str(&entry.to_string());
+        contents.push('\n');
+    }
+
+    fs::write(path, contents)
+}
+
+/// Picks the commit to compare against.
+///
+/// If `requested` is set, it is matched as a prefix of the recorded commits.
+/// Otherwise the most recently recorded commit other than `current` is used.
+pub fn find_baseline(entries: &[Entry], requested: Option<&str>, current: &str) -> Option<String> {
+    entries
+        .iter()
+        .rev()
+        .find(|entry| match requested {
+            Some(requested) => entry.commit.starts_with(requested),
+            None => entry.commit != current,
+        })
+        .map(|entry| entry.commit.clone())
+}
+
+/// A part whose median got slower than the baseline by more than the threshold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regression {
+    pub year: i16,
+    pub day: u8,
+    pub part: u8,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Regression {
+    /// Relative slowdown in percent.
+    pub fn slowdown(&self) -> f64 {
+        (self.current.as_secs_f64() / self.baseline.as_secs_f64() - 1_f64) * 100_f64
+    }
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} day {:02} part {}: median {:.2?} -> {:.2?} ({:+.1}%)",
+            self.year,
+            self.day,
+            self.part,
+            self.baseline,
+            self.current,
+            self.slowdown()
+        )
+    }
+}
+
+/// Parses the allowed slowdown in percent, e.g. `10` or `2.5`.
+pub fn parse_threshold(s: &str) -> Result<f64, String> {
+    match s.parse::<f64>() {
+        Ok(threshold) if threshold.is_finite() && threshold >= 0_f64 => Ok(threshold),
+        _ => Err(format!(
+            "invalid threshold \"{}\", expected a percentage of at least 0",
+            s
+        )),
+    }
+}
+
+/// The result of comparing a run against a baseline.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Comparison {
+    pub regressions: Vec<Regression>,
+    /// Year, day and part of the benchmarked parts that have no entry in the baseline.
+    pub missing: Vec<(i16, u8, u8)>,
+}
+
+/// Compares the medians of `reports` against the entries of `baseline`.
+/// `threshold` is the allowed slowdown in percent.
+pub fn compare(
+    entries: &[Entry],
+    baseline: &str,
+    reports: &[BenchReport],
+    threshold: f64,
+) -> Comparison {
+    let mut comparison = Comparison::default();
+
+    for report in reports {
+        let Some(stats) = report.stats else {
+            continue;
+        };
+        let entry = entries.iter().find(|e| {
+            e.commit == baseline
+                && e.year == report.year
+                && e.day == report.day
+                && e.part == report.part
+        });
+        let Some(entry) = entry else {
+            comparison
+                .missing
+                .push((report.year, report.day, report.part));
+            continue;
+        };
+
+        let (baseline, current) = (entry.stats.median, stats.median);
+        let limit = baseline.as_secs_f64() * (1_f64 + threshold / 100_f64);
+        if current.as_secs_f64() > limit {
+            comparison.regressions.push(Regression {
+                year: report.year,
+                day: report.day,
+                part: report.part,
+                baseline,
+                current,
+            });
+        }
+    }
+
+    comparison
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{temp::TempPath, SolveOutcome};
+
+    fn report(day: u8, part: u8, median: u64) -> BenchReport {
+        let median = Duration::from_micros(median);
+        BenchReport {
+            year: 2022,
+            day,
+            part,
+            outcome: SolveOutcome::NotImplemented,
+            stats: Some(Stats {
+                samples: 10,
+                min: median,
+                median,
+                mean: median,
+                stddev: Duration::ZERO,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_save_load_and_compare() {
+        let path = TempPath::new("bench-history.csv");
+
+        save(&path, "aaaaaaa", &[report(11, 2, 100), report(12, 1, 50)]).unwrap();
+        save(&path, "bbbbbbb", &[report(11, 2, 90)]).unwrap();
+        // re-running a commit replaces its results.
+        save(&path, "bbbbbbb", &[report(11, 2, 95)]).unwrap();
+
+        let entries = load(&path).unwrap();
+
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[2].stats.median, Duration::from_micros(95));
+
+        assert_eq!(
+            find_baseline(&entries, None, "ccccccc"),
+            Some("bbbbbbb".into())
+        );
+        assert_eq!(
+            find_baseline(&entries, None, "bbbbbbb"),
+            Some("aaaaaaa".into())
+        );
+        assert_eq!(
+            find_baseline(&entries, Some("aaa"), "ccccccc"),
+            Some("aaaaaaa".into())
+        );
+        assert_eq!(find_baseline(&entries, Some("fff"), "ccccccc"), None);
+
+        let current = [report(11, 2, 120), report(12, 1, 52), report(13, 1, 999)];
+        let comparison = compare(&entries, "aaaaaaa", &current, 10_f64);
+        let regressions = comparison.regressions;
+
+        assert_eq!(comparison.missing, vec![(2022, 13, 1)]);
+        assert_eq!(
+            regressions,
+            vec![Regression {
+                year: 2022,
+                day: 11,
+                part: 2,
+                baseline: Duration::from_micros(100),
+                current: Duration::from_micros(120),
+            }]
+        );
+        assert!((regressions[0].slowdown() - 20_f64).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn test_parse_threshold() {
+        assert_eq!(parse_threshold("10"), Ok(10_f64));
+        assert_eq!(parse_threshold("0"), Ok(0_f64));
+        assert_eq!(parse_threshold("2.5"), Ok(2.5_f64));
+        assert!(parse_threshold("-1").is_err());
+        assert!(parse_threshold("NaN").is_err());
+        assert!(parse_threshold("inf").is_err());
+        assert!(parse_threshold("ten").is_err());
+    }
+
+    #[test]
+    fn test_save_keeps_other_days() {
+        let path = TempPath::new("bench-days.csv");
+
+        save(&path, "aaaaaaa", &[report(11, 1, 100), report(11, 2, 200)]).unwrap();
+        save(&path, "aaaaaaa", &[report(12, 1, 50)]).unwrap();
+        save(&path, "aaaaaaa", &[report(11, 2, 210)]).unwrap();
+
+        let entries = load(&path).unwrap();
+
+        let medians: Vec<(u8, u8, Duration)> = entries
+            .iter()
+            .map(|entry| (entry.day, entry.part, entry.stats.median))
+            .collect();
+        assert_eq!(
+            medians,
+            vec![
+                (11, 1, Duration::from_micros(100)),
+                (12, 1, Duration::from_micros(50)),
+                (11, 2, Duration::from_micros(210)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_load_malformed() {
+        let path = TempPath::new("bench-malformed.csv");
+        fs::write(&path, format!("{}\nnot,an,entry\n", HEADER)).unwrap();
+
+        assert!(load(&path).is_err());
+    }
+}
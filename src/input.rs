@@ -0,0 +1,174 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    env,
+    error::Error,
+    fmt::{self, Display},
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+use crate::year_path;
+
+/// Environment variable with a directory containing the puzzle inputs as `<year>/NN.txt`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where an explicitly requested input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Path(PathBuf),
+    Stdin,
+}
+
+impl From<&str> for InputSource {
+    /// `-` selects stdin, anything else is a path.
+    fn from(value: &str) -> Self {
+        match value {
+            "-" => InputSource::Stdin,
+            path => InputSource::Path(path.into()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// None of the candidate paths could be read.
+    NotFound {
+        year: i16,
+        day: u8,
+        tried: Vec<(PathBuf, io::Error)>,
+    },
+    Stdin {
+        year: i16,
+        day: u8,
+        source: io::Error,
+    },
+    /// The input file exists but has not been filled in yet, e.g. right after scaffolding.
+    Empty { year: i16, day: u8, path: PathBuf },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound { year, day, tried } => {
+                write!(
+                    f,
+                    "could not read the input for day {:02} of {}, tried:",
+                    day, year
+                )?;
+                for (path, e) in tried {
+                    write!(f, "\n  - \"{}\": {}", path.display(), e)?;
+                }
+                Ok(())
+            }
+            InputError::Stdin { year, day, source } => write!(
+                f,
+                "could not read the input for day {:02} of {} from stdin: {}",
+                day, year, source
+            ),
+            InputError::Empty { year, day, path } => write!(
+                f,
+                "the input for day {:02} of {} in \"{}\" is empty, download it with `cargo download {} --year {}`",
+                day,
+                year,
+                path.display(),
+                day,
+                year
+            ),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+/// Returns the paths checked for the input of `day` of `year` if no source is given explicitly:
+/// `$AOC_INPUT_DIR/<year>/NN.txt` if the variable is set, then `src/inputs/<year>/NN.txt` in the project directory.
+pub fn candidates(year: i16, day: u8) -> Vec<PathBuf> {
+    let file = format!("{:02}.txt", day);
+    let mut paths = Vec::new();
+
+    if let Some(dir) = env::var_os(INPUT_DIR_VAR).filter(|dir| !dir.is_empty()) {
+        paths.push(PathBuf::from(dir).join(year.to_string()).join(&file));
+    }
+    paths.push(year_path("inputs", year).join(file));
+
+    paths
+}
+
+/// Reads the puzzle input of `day` of `year`.
+///
+/// An explicit `source` is used as is. Otherwise the [`candidates`] are tried in order.
+pub fn read_input(year: i16, day: u8, source: Option<&InputSource>) -> Result<String, InputError> {
+    let paths = match source {
+        Some(InputSource::Stdin) => {
+            let mut input = String::new();
+            return match io::stdin().read_to_string(&mut input) {
+                Ok(_) => Ok(input),
+                Err(source) => Err(InputError::Stdin { year, day, source }),
+            };
+        }
+        Some(InputSource::Path(path)) => vec![path.clone()],
+        None => candidates(year, day),
+    };
+
+    let mut tried = Vec::new();
+
+    for path in paths {
+        match fs::read_to_string(&path) {
+            Ok(input) if input.trim().is_empty() => {
+                return Err(InputError::Empty { year, day, path })
+            }
+            Ok(input) => return Ok(input),
+            Err(e) => tried.push((path, e)),
+        }
+    }
+
+    Err(InputError::NotFound { year, day, tried })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp::TempPath;
+
+    #[test]
+    fn test_input_source() {
+        assert_eq!(InputSource::from("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from("in.txt"),
+            InputSource::Path("in.txt".into())
+        );
+    }
+
+    #[test]
+    fn test_read_input() {
+        let path = TempPath::new("input.txt");
+        let source = InputSource::Path(path.to_path_buf());
+        fs::write(&path, "1\n2\n").unwrap();
+        assert_eq!(read_input(2022, 1, Some(&source)).unwrap(), "1\n2\n");
+
+        fs::remove_file(&path).unwrap();
+        let error = read_input(2022, 7, Some(&source)).unwrap_err();
+        let message = error.to_string();
+        assert!(message.starts_with("could not read the input for day 07 of 2022, tried:"));
+        assert!(message.contains(&path.display().to_string()));
+
+        fs::write(&path, "\n").unwrap();
+        assert!(matches!(
+            read_input(2022, 7, Some(&source)),
+            Err(InputError::Empty { day: 7, .. })
+        ));
+    }
+
+    #[test]
+    fn test_candidates() {
+        let paths = candidates(2021, 3);
+        assert_eq!(
+            paths.last(),
+            Some(&crate::project_path("src/inputs/2021/03.txt"))
+        );
+    }
+}
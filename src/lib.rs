@@ -3,57 +3,234 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
-use std::env;
+use std::any::Any;
 use std::fs;
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Duration;
 
+mod answer;
+pub mod bench;
+pub mod client;
+pub mod days;
+pub mod examples;
 pub mod helpers;
+pub mod history;
+pub mod input;
+mod manifest;
+pub mod parse;
+pub mod puzzle;
+pub mod readme;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+mod solution;
+pub mod submit;
+#[cfg(test)]
+mod temp;
+pub mod template;
+pub mod timing;
+pub mod verify;
+pub mod watch;
+
+pub use answer::{Answer, SolveOutcome};
+pub use examples::read_example;
+pub use input::InputSource;
+pub use parse::ParseError;
+pub use solution::Solution;
+pub use timing::Timed;
+
+/// Puzzle year used when no `--year` is given.
+pub const DEFAULT_YEAR: i16 = 2022;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Runs a part against an input, prints its outcome and evaluates to the elapsed `Duration`.
 #[macro_export]
 macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
-            }
+    ($part:expr, $solver:expr, $input:expr) => {{
+        $crate::write_result(&mut std::io::stdout(), $part, $solver, $input)
+            .expect("could not write result to stdout")
+    }};
+}
+
+/// Runs `func` against `input` and writes the part header and its timed outcome to `out`.
+/// Returns the raw execution time of `func`.
+pub fn write_result<T: Into<SolveOutcome>>(
+    out: &mut impl Write,
+    part: u8,
+    func: impl FnOnce(&str) -> T,
+    input: &str,
+) -> io::Result<Duration> {
+    let result = Timed::measure(|| func(input).into());
+    write_outcome(out, part, &result)?;
+    Ok(result.elapsed)
+}
+
+/// Writes the part header and an already timed outcome to `out`.
+pub fn write_outcome(
+    out: &mut impl Write,
+    part: u8,
+    result: &Timed<SolveOutcome>,
+) -> io::Result<()> {
+    writeln!(out, "🎄 {}Part {}{} 🎄", ANSI_BOLD, part, ANSI_RESET)?;
+
+    match &result.value {
+        SolveOutcome::Solved(answer) => {
+            // multi-line answers get their own lines so the drawing stays aligned.
+            let separator = if answer.is_multiline() { "\n" } else { " " };
+            writeln!(
+                out,
+                "{}{}{}(elapsed: {:.2?}){}",
+                answer, separator, ANSI_ITALIC, result.elapsed, ANSI_RESET
+            )
         }
+        outcome => writeln!(out, "{}", outcome),
+    }
+}
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+/// Runs `part` of `solution` against `input` and measures its raw execution time.
+///
+/// A panic in the part is caught and reported as [`SolveOutcome::Panicked`].
+pub fn solve_part(solution: &dyn Solution, part: u8, input: &str) -> Timed<SolveOutcome> {
+    Timed::measure(|| {
+        panic::catch_unwind(AssertUnwindSafe(|| solution.solve(part, input)))
+            .unwrap_or_else(|payload| SolveOutcome::Panicked(panic_message(payload.as_ref())))
+    })
+}
+
+/// Extracts the message of a panic, which is a `&str` or a `String` unless the panic was raised with a custom payload.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".into()
+    }
+}
+
+/// Runs both parts of `solution` against its puzzle input, prints the results
+/// and returns the combined execution time.
+///
+/// The input is read from `source` if given, otherwise it is resolved by [`input::read_input`].
+/// Parts that take longer than `timeout` are reported as timed out, see [`runner::solve_part_within`].
+pub fn run(
+    solution: &'static dyn Solution,
+    source: Option<&InputSource>,
+    timeout: Option<Duration>,
+) -> Duration {
+    let input = &match input::read_input(solution.year(), solution.day(), source) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to read input: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let mut stdout = io::stdout();
+    [1, 2]
+        .into_iter()
+        .map(|part| {
+            let result = runner::solve_part_within(solution, part, input, timeout);
+            write_outcome(&mut stdout, part, &result).expect("could not write result to stdout");
+            result.elapsed
+        })
+        .sum()
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+/// Entry point of the per-day binaries in `src/bin/NN.rs`.
+/// Reads `--input` and `--timeout` like `cargo solve` does and runs both parts with [`run`].
+pub fn run_bin(solution: &'static dyn Solution) {
+    match parse_bin_args() {
+        Ok((input, timeout)) => {
+            run(solution, input.as_ref(), timeout);
+        }
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            eprintln!(
+                "Usage: `cargo run --bin {:02} -- [--input <path>|-] [--timeout <seconds>]`",
+                solution.day()
+            );
+            process::exit(1);
+        }
+    }
+}
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+fn parse_bin_args() -> Result<(Option<InputSource>, Option<Duration>), pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let input = args
+        .opt_value_from_str::<_, String>("--input")?
+        .as_deref()
+        .map(InputSource::from);
+    let timeout = args.opt_value_from_fn("--timeout", runner::parse_timeout)?;
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    finish_args(args)?;
+    Ok((input, timeout))
+}
+
+/// Checks that all command line arguments were consumed, call it after parsing the last option.
+/// Left over arguments, e.g. a mistyped flag, are reported as an error instead of being ignored.
+pub fn finish_args(args: pico_args::Arguments) -> Result<(), pico_args::Error> {
+    let unused = args.finish();
+    if unused.is_empty() {
+        return Ok(());
+    }
+
+    Err(pico_args::Error::Utf8ArgumentParsingFailed {
+        value: unused
+            .iter()
+            .map(|arg| arg.to_string_lossy())
+            .collect::<Vec<_>>()
+            .join(" "),
+        cause: "unexpected argument".into(),
+    })
+}
+
+/// Returns the path of `rel` in the project directory, so files are found independent of the current working directory.
+pub fn project_path(rel: impl AsRef<Path>) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(rel)
+}
+
+/// Returns the path of `src/<folder>` in the project directory.
+pub fn src_path(folder: &str) -> PathBuf {
+    project_path("src").join(folder)
+}
+
+/// Returns the path of `src/<folder>/<year>`, where inputs, examples and answers of a year are kept.
+pub fn year_path(folder: &str, year: i16) -> PathBuf {
+    src_path(folder).join(year.to_string())
+}
+
+/// Reads the file at `path`, a missing file is `None` instead of an error.
+pub fn read_optional(path: &Path) -> io::Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Reads `src/<folder>/<year>/NN.txt` with [`input::read_input`], so a missing or empty file is reported the same way.
+pub fn read_file(folder: &str, year: i16, day: u8) -> Result<String, input::InputError> {
+    let path = year_path(folder, year).join(format!("{:02}.txt", day));
+
+    input::read_input(year, day, Some(&InputSource::Path(path)))
 }
 
 fn parse_time(val: &str, postfix: &str) -> f64 {
     val.split(postfix).next().unwrap().parse().unwrap()
 }
 
+/// Sums the `(elapsed: ...)` timings printed by [`solve!`] in milliseconds.
+///
+/// Only kept for compatibility with tooling that scrapes solution output:
+/// nanosecond timings are dropped and output is parsed back from text.
+/// Prefer the `Duration` returned by [`solve!`], [`write_result`] and [`solve_part`].
 pub fn parse_exec_time(output: &str) -> f64 {
     output.lines().fold(0_f64, |acc, l| {
         if !l.contains("elapsed:") {
@@ -77,6 +254,12 @@ pub fn parse_exec_time(output: &str) -> f64 {
     })
 }
 
+/// Tests generated by `build.rs` from the expected answers in `src/examples/<year>/NN.toml`.
+#[cfg(test)]
+mod example_tests {
+    include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
+}
+
 /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
 #[cfg(test)]
 macro_rules! assert_approx_eq {
@@ -122,4 +305,84 @@ mod tests {
             10400.50_f64
         );
     }
+
+    #[test]
+    fn test_write_result() {
+        let mut output = Vec::new();
+        let elapsed = write_result(&mut output, 1, |input| Some(input.len()), "12345").unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains("5 "));
+        assert!(parse_exec_time(&output) <= timing::as_millis_f64(elapsed) + 0.01);
+
+        let mut output = Vec::new();
+        write_result(&mut output, 2, |_| None::<u32>, "").unwrap();
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .ends_with("not solved.\n"));
+    }
+
+    #[test]
+    fn test_finish_args() {
+        let args = pico_args::Arguments::from_vec(vec!["--days".into(), "5".into()]);
+        assert!(finish_args(args).is_err());
+
+        let mut args = pico_args::Arguments::from_vec(vec!["--dayz".into(), "5".into()]);
+        let days: Option<String> = args.opt_value_from_str("--days").unwrap();
+        assert_eq!(days, None);
+        assert_eq!(
+            finish_args(args).unwrap_err().to_string(),
+            "failed to parse '--dayz 5': unexpected argument"
+        );
+
+        let mut args = pico_args::Arguments::from_vec(vec!["--days".into(), "5".into()]);
+        let days: Option<String> = args.opt_value_from_str("--days").unwrap();
+        assert_eq!(days.as_deref(), Some("5"));
+        assert!(finish_args(args).is_ok());
+    }
+
+    #[test]
+    fn test_read_optional() {
+        assert!(read_optional(&project_path("Cargo.toml"))
+            .unwrap()
+            .is_some());
+        assert_eq!(read_optional(&project_path("missing.txt")).unwrap(), None);
+        assert!(read_optional(&project_path("src")).is_err());
+    }
+
+    #[test]
+    fn test_solve_part_catches_panics() {
+        struct Panics;
+
+        impl Solution for Panics {
+            fn year(&self) -> i16 {
+                DEFAULT_YEAR
+            }
+
+            fn day(&self) -> u8 {
+                1
+            }
+
+            fn title(&self) -> &'static str {
+                "Panics"
+            }
+
+            fn part_one(&self, input: &str) -> SolveOutcome {
+                panic!("unexpected {:?}", input)
+            }
+
+            fn part_two(&self, input: &str) -> SolveOutcome {
+                Some(input.parse::<u32>().unwrap()).into()
+            }
+        }
+
+        assert_eq!(
+            solve_part(&Panics, 1, "x").value,
+            SolveOutcome::Panicked("unexpected \"x\"".into())
+        );
+        assert!(matches!(
+            solve_part(&Panics, 2, "x").value,
+            SolveOutcome::Panicked(message) if message.contains("InvalidDigit")
+        ));
+    }
 }
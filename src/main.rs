@@ -2,45 +2,383 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::{
+    bench::{self, BenchConfig},
+    days, finish_args, history,
+    input::{read_input, InputError},
+    readme,
+    report::{self, Format, PartReport},
+    runner::{map_in_order, parse_part, parse_timeout, solve_part_within, Selection},
+    timing,
+    verify::{self, RecordedAnswers, Verdict},
+    write_outcome, Solution, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, DEFAULT_YEAR,
+};
+use std::{io, path::PathBuf, process, time::Duration};
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
-
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
-
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
-
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
-
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
+struct Args {
+    format: Format,
+    record: bool,
+    bench: Option<BenchConfig>,
+    history: PathBuf,
+    save_history: bool,
+    baseline: Option<String>,
+    threshold: f64,
+    year: i16,
+    selection: Selection,
+    jobs: usize,
+    timeout: Option<Duration>,
+    update_readme: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let defaults = BenchConfig::default();
+
+    let format = args
+        .opt_value_from_str(["-f", "--format"])?
+        .unwrap_or(Format::Text);
+    let record = args.contains("--record");
+    let bench = args.contains("--bench");
+    let config = BenchConfig {
+        warmup: args
+            .opt_value_from_str("--warmup")?
+            .unwrap_or(defaults.warmup),
+        iterations: args
+            .opt_value_from_str("--iterations")?
+            .unwrap_or(defaults.iterations),
+        budget: args
+            .opt_value_from_fn("--budget", bench::parse_budget)?
+            .unwrap_or(defaults.budget),
+    };
+
+    let parsed = Args {
+        format,
+        record,
+        bench: bench.then_some(config),
+        history: args
+            .opt_value_from_str("--history")?
+            .unwrap_or_else(history::default_path),
+        save_history: !args.contains("--no-save"),
+        baseline: args.opt_value_from_str("--baseline")?,
+        threshold: args
+            .opt_value_from_fn("--threshold", history::parse_threshold)?
+            .unwrap_or(10_f64),
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(DEFAULT_YEAR),
+        selection: Selection {
+            days: args.opt_value_from_str(["-d", "--days"])?,
+            part: args.opt_value_from_fn(["-p", "--part"], parse_part)?,
+        },
+        jobs: args
+            .opt_value_from_str(["-j", "--jobs"])?
+            .unwrap_or(1_usize)
+            .max(1),
+        timeout: args.opt_value_from_fn("--timeout", parse_timeout)?,
+        update_readme: args.contains("--update-readme"),
+    };
+
+    finish_args(args)?;
+    Ok(parsed)
+}
+
+fn print_day_header(day: u8) {
+    println!("----------");
+    println!("{}| Day {:02} |{}", ANSI_BOLD, day, ANSI_RESET);
+    println!("----------");
+}
+
+/// Outcome of running the selected parts of a single day.
+struct DayRun {
+    year: i16,
+    day: u8,
+    recorded: RecordedAnswers,
+    /// The reports of the selected parts, or why the day's input could not be read.
+    reports: Result<Vec<PartReport>, InputError>,
+}
+
+fn run_day(
+    solution: &'static dyn Solution,
+    parts: &[u8],
+    timeout: Option<Duration>,
+) -> io::Result<DayRun> {
+    let (year, day) = (solution.year(), solution.day());
+    let recorded = verify::load(year, day)?;
+
+    let reports = read_input(year, day, None).map(|input| {
+        parts
+            .iter()
+            .map(|&part| PartReport {
+                year,
+                day,
+                part,
+                expected: recorded.get(part).cloned(),
+                result: solve_part_within(solution, part, &input, timeout),
+            })
+            .collect()
+    });
+
+    Ok(DayRun {
+        year,
+        day,
+        recorded,
+        reports,
+    })
+}
+
+/// Returns the registered solutions of `year` included in `selection` and warns about selected days that are not implemented.
+fn selected_solutions(year: i16, selection: &Selection) -> Vec<&'static dyn Solution> {
+    if days::year(year).next().is_none() {
+        eprintln!("No solutions of {} are registered in src/days.", year);
+    }
+    if let Some(days) = &selection.days {
+        for day in days.0.iter().filter(|&&day| days::get(year, day).is_none()) {
+            eprintln!("Day {:02} of {} is not implemented, skipping.", day, year);
+        }
+    }
+
+    days::year(year)
+        .filter(|solution| selection.includes_day(solution.day()))
+        .collect()
+}
+
+fn run_all(args: &Args) {
+    let is_text = args.format == Format::Text;
+    let parts = args.selection.parts();
+    let mut stdout = io::stdout();
+    let mut reports = Vec::new();
+
+    let solutions = selected_solutions(args.year, &args.selection);
+
+    map_in_order(
+        &solutions,
+        args.jobs,
+        |solution| run_day(*solution, &parts, args.timeout),
+        |run| {
+            let DayRun {
+                year,
+                day,
+                mut recorded,
+                reports: day_reports,
+            } = match run {
+                Ok(run) => run,
+                Err(e) => {
+                    eprintln!("Failed to load recorded answers: {}", e);
+                    process::exit(1);
+                }
+            };
+
+            if is_text {
+                print_day_header(day);
+            }
+
+            let day_reports = match day_reports {
+                Ok(day_reports) => day_reports,
+                Err(e) => {
+                    if is_text {
+                        println!("Not solved: {}", e);
+                    } else {
+                        eprintln!("Day {:02} of {} not solved: {}", day, year, e);
+                    }
+                    reports.extend(
+                        parts
+                            .iter()
+                            .map(|&part| PartReport::unsolved(year, day, part)),
+                    );
+                    return;
+                }
+            };
+
+            for report in day_reports {
+                if is_text {
+                    write_outcome(&mut stdout, report.part, &report.result).unwrap();
+                    match report.verdict() {
+                        Verdict::Correct => println!("✔ matches recorded answer"),
+                        Verdict::Wrong { expected } => {
+                            println!("✘ WRONG: recorded answer is {}", expected)
+                        }
+                        Verdict::Unknown => {}
+                    }
+                }
+
+                if args.record {
+                    if let Some(answer) = report.result.value.answer() {
+                        recorded.set(report.part, answer.clone());
+                    }
+                }
+
+                reports.push(report);
+            }
+
+            if args.record {
+                match verify::save(year, day, &recorded) {
+                    Ok(path) => eprintln!("Recorded answers to \"{}\"", path.display()),
+                    Err(e) => {
+                        eprintln!("Failed to record answers: {}", e);
+                        process::exit(1);
+                    }
+                }
+            }
+        },
+    );
+
+    match args.format {
+        Format::Text => println!(
+            "{}Total:{} {}{:.2}ms{}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            ANSI_ITALIC,
+            timing::as_millis_f64(report::total_elapsed(&reports)),
+            ANSI_RESET
+        ),
+        Format::Json => report::write_json(&mut stdout, &reports).unwrap(),
+        Format::Csv => report::write_csv(&mut stdout, &reports).unwrap(),
+    }
+
+    if args.update_readme {
+        let path = readme::path();
+        match readme::update(&path, &reports) {
+            Ok(()) => eprintln!("Updated timing table in \"{}\"", path.display()),
+            Err(e) => {
+                eprintln!("Failed to update readme: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    let wrong: Vec<String> = reports
+        .iter()
+        .filter(|report| matches!(report.verdict(), Verdict::Wrong { .. }))
+        .map(|report| format!("day {:02} part {}", report.day, report.part))
+        .collect();
+
+    if !wrong.is_empty() && !args.record {
+        eprintln!(
+            "{} part(s) did not match their recorded answers: {}",
+            wrong.len(),
+            wrong.join(", ")
+        );
+        process::exit(1);
+    }
+}
+
+fn bench_all(args: &Args, config: &BenchConfig) {
+    let is_text = args.format == Format::Text;
+    let mut stdout = io::stdout();
+    let mut reports = Vec::new();
+
+    for solution in selected_solutions(args.year, &args.selection) {
+        let day = solution.day();
+
+        let input = match read_input(args.year, day, None) {
+            Ok(input) => input,
+            Err(e) => {
+                if is_text {
+                    println!("{}Day {:02}:{} no input, {}", ANSI_BOLD, day, ANSI_RESET, e);
                 } else {
-                    output.trim()
+                    eprintln!("Day {:02} of {} not benchmarked: {}", day, args.year, e);
                 }
+                continue;
+            }
+        };
+
+        for part in args.selection.parts() {
+            let report = bench::bench_part(solution, part, &input, config);
+            if is_text {
+                bench::write_text(&mut stdout, &report).unwrap();
+            }
+            reports.push(report);
+        }
+    }
+
+    match args.format {
+        Format::Text => {}
+        Format::Json => bench::write_json(&mut stdout, &reports).unwrap(),
+        Format::Csv => bench::write_csv(&mut stdout, &reports).unwrap(),
+    }
+
+    let entries = match history::load(&args.history) {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("Failed to load benchmark history: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let commit = history::current_commit();
+    let regressions = match history::find_baseline(&entries, args.baseline.as_deref(), &commit) {
+        Some(baseline) => {
+            let comparison = history::compare(&entries, &baseline, &reports, args.threshold);
+            eprintln!(
+                "Compared against baseline {}: {} regression(s) above {}%.",
+                baseline,
+                comparison.regressions.len(),
+                args.threshold
             );
+            if !comparison.missing.is_empty() {
+                let missing: Vec<String> = comparison
+                    .missing
+                    .iter()
+                    .map(|(year, day, part)| format!("{} day {:02} part {}", year, day, part))
+                    .collect();
+                eprintln!(
+                    "{} part(s) have no results in baseline {} to compare against: {}",
+                    missing.len(),
+                    baseline,
+                    missing.join(", ")
+                );
+            }
+            comparison.regressions
+        }
+        None => {
+            if let Some(baseline) = &args.baseline {
+                eprintln!("Baseline \"{}\" not found in benchmark history.", baseline);
+                process::exit(1);
+            }
+            eprintln!("No baseline in benchmark history yet, skipping comparison.");
+            Vec::new()
+        }
+    };
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+    if args.save_history {
+        match history::save(&args.history, &commit, &reports) {
+            Ok(_) => eprintln!(
+                "Saved results for {} to \"{}\"",
+                commit,
+                args.history.display()
+            ),
+            Err(e) => {
+                eprintln!("Failed to save benchmark history: {}", e);
+                process::exit(1);
             }
-        })
-        .sum();
+        }
+    }
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
-    );
+    if !regressions.is_empty() {
+        for regression in &regressions {
+            eprintln!("✘ REGRESSION {}", regression);
+        }
+        process::exit(1);
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            eprintln!(
+                "Usage: `cargo all -- [--year <year>] [--days <days>] [--part <1|2>] [--jobs <n>] [--timeout <seconds>] [--format text|json|csv] [--record] [--update-readme]`"
+            );
+            eprintln!(
+                "       `cargo bench-days -- [--year <year>] [--days <days>] [--part <1|2>] [--warmup <n>] [--iterations <n>] [--budget <seconds>] [--history <path>] [--baseline <commit>] [--threshold <percent>] [--no-save] [--format text|json|csv]`"
+            );
+            process::exit(1);
+        }
+    };
+
+    match &args.bench {
+        Some(config) => bench_all(&args, config),
+        None => run_all(&args),
+    }
 }
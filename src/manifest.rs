@@ -0,0 +1,107 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ *
+ * `build.rs` includes this file to generate the example tests, so it must not depend on the rest of the crate.
+ */
+
+/// A `part_one = ...` or `part_two = ...` key of an answers file, with its value as written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry<'a> {
+    /// 1-based line of the key, for error messages.
+    pub line: usize,
+    pub part: u8,
+    pub value: &'a str,
+}
+
+/// The keys of an answers file up to the next `[name]` header.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Section<'a> {
+    /// `None` for the keys before the first header.
+    pub name: Option<&'a str>,
+    pub entries: Vec<Entry<'a>>,
+}
+
+/// Splits an answers file, which uses a small subset of TOML, into its sections.
+/// The unnamed section comes first, even if it is empty. Blank lines and `#` comments are skipped.
+pub fn parse(contents: &str) -> Result<Vec<Section<'_>>, String> {
+    let mut sections = vec![Section::default()];
+
+    for (i, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[') {
+            let name = name
+                .strip_suffix(']')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .ok_or_else(|| format!("line {}: expected `[name]`", i + 1))?;
+            sections.push(Section {
+                name: Some(name),
+                entries: Vec::new(),
+            });
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("line {}: expected `key = value`", i + 1))?;
+        let part = match key.trim() {
+            "part_one" => 1,
+            "part_two" => 2,
+            key => return Err(format!("line {}: unknown key \"{}\"", i + 1, key)),
+        };
+
+        sections.last_mut().unwrap().entries.push(Entry {
+            line: i + 1,
+            part,
+            value: value.trim(),
+        });
+    }
+
+    Ok(sections)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let sections =
+            parse("# answers\npart_one = 21\n\n[top_bottom]\npart_two = \"a=b\"\n").unwrap();
+        assert_eq!(
+            sections,
+            vec![
+                Section {
+                    name: None,
+                    entries: vec![Entry {
+                        line: 2,
+                        part: 1,
+                        value: "21"
+                    }],
+                },
+                Section {
+                    name: Some("top_bottom"),
+                    entries: vec![Entry {
+                        line: 5,
+                        part: 2,
+                        value: "\"a=b\""
+                    }],
+                },
+            ]
+        );
+
+        assert_eq!(parse("").unwrap(), vec![Section::default()]);
+        assert_eq!(
+            parse("part_one = 1\npart_three = 2"),
+            Err("line 2: unknown key \"part_three\"".into())
+        );
+        assert!(parse("[]\npart_one = 1").is_err());
+        assert!(parse("[name\npart_one = 1").is_err());
+        assert!(parse("part_one 1").is_err());
+    }
+}
@@ -0,0 +1,184 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Longest snippet of the offending text shown in an error, in characters.
+const MAX_SNIPPET: usize = 40;
+
+/// Malformed puzzle input: what was expected, the offending text and, once located, where it is.
+///
+/// Parsers create it with [`ParseError::new`] from the slice of the input they could not parse.
+/// Whoever holds the whole input then calls [`ParseError::locate`] to add the line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    snippet: String,
+    /// Address of the offending text, to find it back in the input it was borrowed from.
+    address: usize,
+    /// 1-based line and column of the offending text.
+    position: Option<(usize, usize)>,
+}
+
+impl ParseError {
+    /// Creates an error about `snippet`, the part of the input that could not be parsed.
+    ///
+    /// Pass a slice of the input rather than a copy, otherwise the error can't be located.
+    /// An empty slice at the end of the text marks missing input.
+    pub fn new(snippet: &str, message: impl Display) -> Self {
+        ParseError {
+            message: message.to_string(),
+            snippet: snippet.to_owned(),
+            address: snippet.as_ptr() as usize,
+            position: None,
+        }
+    }
+
+    /// Adds the line and column of the offending text if it was borrowed from `input`.
+    /// Errors that were already located are returned unchanged.
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        let borrowed =
+            self.address >= start && self.address + self.snippet.len() <= start + input.len();
+
+        if self.position.is_none() && borrowed {
+            let before = &input[..self.address - start];
+            let line = before.matches('\n').count() + 1;
+            let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+            self.position = Some((line, column));
+        }
+
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn snippet(&self) -> &str {
+        &self.snippet
+    }
+
+    /// 1-based line and column of the offending text, `None` until [located](ParseError::locate).
+    pub fn position(&self) -> Option<(usize, usize)> {
+        self.position
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+
+        // only show the first line of multi-line snippets, e.g. a whole block.
+        let line = self.snippet.lines().next().unwrap_or("");
+        if !line.is_empty() {
+            let snippet: String = line.chars().take(MAX_SNIPPET).collect();
+            let ellipsis = if snippet.len() < self.snippet.len() {
+                "…"
+            } else {
+                ""
+            };
+            write!(f, ", found {:?}{}", snippet, ellipsis)?;
+        }
+
+        if let Some((line, column)) = self.position {
+            write!(f, " at line {}, column {}", line, column)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `s` as a number, ignoring surrounding whitespace.
+pub fn number<T>(s: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let trimmed = s.trim();
+    trimmed
+        .parse()
+        .map_err(|e| ParseError::new(trimmed, format!("invalid number ({})", e)))
+}
+
+/// Parses every line of `input` as a `T`, locating the first error in `input`.
+pub fn lines<T: FromStr<Err = ParseError>>(input: &str) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .map(str::parse)
+        .collect::<Result<_, _>>()
+        .map_err(|e: ParseError| e.locate(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Move(char, u8);
+
+    impl FromStr for Move {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let (direction, count) = s
+                .split_once(' ')
+                .ok_or_else(|| ParseError::new(s, "expected a direction and a count"))?;
+            match direction {
+                "U" | "D" | "L" | "R" => {
+                    Ok(Move(direction.chars().next().unwrap(), number(count)?))
+                }
+                _ => Err(ParseError::new(direction, "expected U, D, L or R")),
+            }
+        }
+    }
+
+    #[test]
+    fn test_lines() {
+        assert_eq!(
+            lines::<Move>("R 4\nU 12"),
+            Ok(vec![Move('R', 4), Move('U', 12)])
+        );
+
+        let error = lines::<Move>("R 4\nU 4\nL x1").unwrap_err();
+        assert_eq!(error.position(), Some((3, 3)));
+        assert_eq!(
+            error.to_string(),
+            "invalid number (invalid digit found in string), found \"x1\" at line 3, column 3"
+        );
+
+        let error = lines::<Move>("R 4\n🎄 4").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "expected U, D, L or R, found \"🎄\" at line 2, column 1"
+        );
+    }
+
+    #[test]
+    fn test_locate() {
+        let input = "Monkey 0:\n  Test: divisible by";
+        let missing = ParseError::new(&input[input.len()..], "expected a number");
+        assert_eq!(
+            missing.locate(input).to_string(),
+            "expected a number at line 2, column 21"
+        );
+
+        // copies of the input can't be located.
+        let copy = input.replace("Monkey", "Ape");
+        let error = ParseError::new(&copy, "expected a monkey");
+        assert_eq!(error.clone().locate(input), error);
+        assert_eq!(error.to_string(), "expected a monkey, found \"Ape 0:\"…");
+
+        let long = "x".repeat(50);
+        assert_eq!(
+            ParseError::new(&long, "expected a number").to_string(),
+            format!("expected a number, found \"{}\"…", "x".repeat(40))
+        );
+    }
+}
@@ -0,0 +1,357 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+/// A piece of HTML: a tag or the text between tags.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token<'a> {
+    Open { name: String, attributes: &'a str },
+    Close { name: String },
+    Text(&'a str),
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let (text, tag) = match rest.find('<') {
+            Some(start) => (&rest[..start], &rest[start..]),
+            None => (rest, ""),
+        };
+        if !text.is_empty() {
+            tokens.push(Token::Text(text));
+        }
+
+        let end = match tag.find('>') {
+            Some(end) => end,
+            None => break,
+        };
+        let inner = tag[1..end].trim_end_matches('/').trim();
+        rest = &tag[end + 1..];
+
+        if inner.starts_with('!') {
+            continue;
+        }
+
+        match inner.strip_prefix('/') {
+            Some(name) => tokens.push(Token::Close {
+                name: name.trim().to_ascii_lowercase(),
+            }),
+            None => {
+                let (name, attributes) =
+                    inner.split_once(char::is_whitespace).unwrap_or((inner, ""));
+                tokens.push(Token::Open {
+                    name: name.to_ascii_lowercase(),
+                    attributes,
+                });
+            }
+        }
+    }
+
+    tokens
+}
+
+/// Returns the value of `name` in the attributes of a tag, e.g. `href` in `href="/2022/day/1/input"`.
+fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let start = attributes.find(&format!("{}=\"", name))? + name.len() + 2;
+    let length = attributes[start..].find('"')?;
+    Some(&attributes[start..start + length])
+}
+
+/// Decodes the character references used on the puzzle pages.
+pub fn decode_entities(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest.find(';').and_then(|end| {
+            let entity = &rest[1..end];
+            let ch = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => match entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok().and_then(char::from_u32),
+                    None => entity
+                        .strip_prefix('#')
+                        .and_then(|dec| dec.parse().ok())
+                        .and_then(char::from_u32),
+                },
+            };
+            ch.map(|ch| (ch, end))
+        });
+
+        match decoded {
+            Some((ch, end)) => {
+                result.push(ch);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Returns the inner HTML of every `<article>` on a puzzle page. Part two is only shown once part one is solved.
+pub fn articles(html: &str) -> Vec<&str> {
+    let mut articles = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let body = match rest[start..].find('>') {
+            Some(open_end) => &rest[start + open_end + 1..],
+            None => break,
+        };
+        let end = body.find("</article>").unwrap_or(body.len());
+        articles.push(&body[..end]);
+        rest = &body[end..];
+    }
+
+    articles
+}
+
+/// Starts a new block, unless the output already ends with a blank line.
+fn start_block(out: &mut String) {
+    let trimmed = out.trim_end_matches(' ').len();
+    out.truncate(trimmed);
+
+    if !out.is_empty() && !out.ends_with("\n\n") {
+        out.push_str(if out.ends_with('\n') { "\n" } else { "\n\n" });
+    }
+}
+
+/// Converts the HTML of a puzzle article to Markdown.
+///
+/// Only the handful of elements used on the puzzle pages are translated,
+/// other tags are dropped and their text is kept.
+pub fn to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut pre = 0;
+    let mut links = Vec::new();
+    let mut heading_start = None;
+    // start of the open inline code span and whether it contains emphasis.
+    let mut code_start = None;
+    let mut code_emphasis = false;
+
+    for token in tokenize(html) {
+        match token {
+            Token::Text(text) if pre > 0 => out.push_str(&decode_entities(text)),
+            Token::Text(text) => {
+                // collapse whitespace like a browser does.
+                let text = decode_entities(text);
+                let mut collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                if text.starts_with(char::is_whitespace) {
+                    collapsed.insert(0, ' ');
+                }
+                if text.ends_with(char::is_whitespace) && !text.trim().is_empty() {
+                    collapsed.push(' ');
+                }
+
+                if out.is_empty() || out.ends_with([' ', '\n']) {
+                    out.push_str(collapsed.trim_start());
+                } else {
+                    out.push_str(&collapsed);
+                }
+            }
+            Token::Open { name, attributes } => match name.as_str() {
+                "h1" | "h2" | "h3" => {
+                    start_block(&mut out);
+                    out.push_str("## ");
+                    heading_start = Some(out.len());
+                }
+                "p" | "ul" => start_block(&mut out),
+                "li" => {
+                    if !out.ends_with('\n') && !out.is_empty() {
+                        out.push('\n');
+                    }
+                    out.push_str("- ");
+                }
+                "pre" => {
+                    start_block(&mut out);
+                    out.push_str("```\n");
+                    pre += 1;
+                }
+                // emphasis can't be nested in a code span, the whole span is made bold instead.
+                "em" | "strong" | "b" | "i" if code_start.is_some() => code_emphasis = true,
+                "em" | "strong" | "b" | "i" if pre == 0 => out.push('*'),
+                "code" if pre == 0 => {
+                    code_start = Some(out.len());
+                    code_emphasis = false;
+                    out.push('`');
+                }
+                "a" if pre == 0 => {
+                    out.push('[');
+                    links.push(attribute(attributes, "href").unwrap_or("").to_owned());
+                }
+                "br" => out.push('\n'),
+                _ => {}
+            },
+            Token::Close { name } => match name.as_str() {
+                "h1" | "h2" | "h3" => {
+                    // puzzle titles look like `--- Day 1: Calorie Counting ---`.
+                    if let Some(start) = heading_start.take() {
+                        let title = out[start..].trim().trim_matches('-').trim().to_owned();
+                        out.truncate(start);
+                        out.push_str(&title);
+                    }
+                    out.push_str("\n\n");
+                }
+                "p" | "ul" => out.push_str("\n\n"),
+                "li" => out.push('\n'),
+                "pre" => {
+                    pre -= 1;
+                    if !out.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out.push_str("```\n\n");
+                }
+                "em" | "strong" | "b" | "i" if code_start.is_some() => {}
+                "em" | "strong" | "b" | "i" if pre == 0 => out.push('*'),
+                "code" if pre == 0 => {
+                    out.push('`');
+                    if let Some(start) = code_start.take() {
+                        if code_emphasis {
+                            out.insert_str(start, "**");
+                            out.push_str("**");
+                        }
+                    }
+                }
+                "a" if pre == 0 => {
+                    let href = links.pop().unwrap_or_default();
+                    out.push_str(&format!("]({})", href));
+                }
+                _ => {}
+            },
+        }
+    }
+
+    let mut markdown = out
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n");
+    while markdown.contains("\n\n\n") {
+        markdown = markdown.replace("\n\n\n", "\n\n");
+    }
+
+    markdown.trim().to_owned() + "\n"
+}
+
+/// Converts all articles of a puzzle page to Markdown.
+pub fn page_to_markdown(html: &str) -> String {
+    articles(html)
+        .into_iter()
+        .map(to_markdown)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Returns the text of the first `<pre><code>` block of a puzzle page, which usually is the example input.
+pub fn first_example(html: &str) -> Option<String> {
+    let start = html.find("<pre><code>")? + "<pre><code>".len();
+    let length = html[start..].find("</code></pre>")?;
+
+    let text: String = tokenize(&html[start..start + length])
+        .into_iter()
+        .filter_map(|token| match token {
+            Token::Text(text) => Some(text),
+            _ => None,
+        })
+        .collect();
+
+    Some(decode_entities(&text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>The jungle must be too overgrown and difficult to navigate in vehicles or access from the air; the Elves' expedition traditionally goes on foot.</p>
+<p>For example, suppose the Elves finish writing their items' <em>Calories</em> into a list:</p>
+<pre><code>1000
+2000
+
+<em>3000</em>
+</code></pre>
+<p>This list represents the Calories of the food carried by <code>3</code> Elves &amp; more:</p>
+<ul>
+<li>The first Elf is carrying food with <code>1000</code> Calories.</li>
+<li>The second Elf is carrying <a href="/2022/day/1/input">one item</a>.</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>24000</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>By the time you calculate the answer &lt;to&gt; the Elves' question...</p></article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_page_to_markdown() {
+        assert_eq!(
+            page_to_markdown(PAGE),
+            "## Day 1: Calorie Counting
+
+The jungle must be too overgrown and difficult to navigate in vehicles or access from the air; the Elves' expedition traditionally goes on foot.
+
+For example, suppose the Elves finish writing their items' *Calories* into a list:
+
+```
+1000
+2000
+
+3000
+```
+
+This list represents the Calories of the food carried by `3` Elves & more:
+
+- The first Elf is carrying food with `1000` Calories.
+- The second Elf is carrying [one item](/2022/day/1/input).
+
+## Part Two
+
+By the time you calculate the answer <to> the Elves' question...
+"
+        );
+    }
+
+    #[test]
+    fn test_emphasis_in_code() {
+        assert_eq!(
+            to_markdown("<p>It is <code><em>24000</em></code> or <code>1<em>2</em></code>.</p>"),
+            "It is **`24000`** or **`12`**.\n"
+        );
+        assert_eq!(
+            to_markdown("<p>It is <em><code>24000</code></em>.</p>"),
+            "It is *`24000`*.\n"
+        );
+    }
+
+    #[test]
+    fn test_first_example() {
+        assert_eq!(first_example(PAGE), Some("1000\n2000\n\n3000\n".into()));
+        assert_eq!(first_example("<p>no example</p>"), None);
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(
+            decode_entities("a &lt;b&gt; &amp;&#39;&#x27; & c;"),
+            "a <b> &'' & c;"
+        );
+    }
+}
@@ -0,0 +1,273 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{project_path, report::PartReport, SolveOutcome};
+
+pub const TABLE_START: &str = "<!--- benchmarking table --->";
+pub const TABLE_END: &str = "<!--- benchmarking table end --->";
+
+/// Location of the readme in the project root.
+pub fn path() -> PathBuf {
+    project_path("README.md")
+}
+
+/// Timings of part 1 and part 2 of a day, `None` for parts that are not solved.
+pub type DayTimings = [Option<Duration>; 2];
+
+/// Timings of every day in the table, keyed by year and day.
+pub type Timings = BTreeMap<(i16, u8), DayTimings>;
+
+fn format_elapsed(elapsed: Option<Duration>) -> String {
+    match elapsed {
+        Some(elapsed) => format!("`{:.2?}`", elapsed),
+        None => "-".into(),
+    }
+}
+
+/// Parses a timing like `1.50ms`, as written by [`format_elapsed`].
+fn parse_elapsed(s: &str) -> Option<Duration> {
+    let (value, unit) = s.split_at(s.find(|c: char| !c.is_ascii_digit() && c != '.')?);
+    let nanos = match unit {
+        "ns" => 1_f64,
+        "µs" => 1_000_f64,
+        "ms" => 1_000_000_f64,
+        "s" => 1_000_000_000_f64,
+        _ => return None,
+    };
+    Some(Duration::from_nanos(
+        (value.parse::<f64>().ok()? * nanos).round() as u64,
+    ))
+}
+
+fn parse_cell(cell: &str) -> Option<Option<Duration>> {
+    match cell {
+        "-" => Some(None),
+        cell => parse_elapsed(cell.strip_prefix('`')?.strip_suffix('`')?).map(Some),
+    }
+}
+
+/// Parses a row like `| [Day 1](./src/days/y2022/day01.rs) | `10.00µs` | - | `10.00µs` |`.
+fn parse_row(line: &str) -> Option<((i16, u8), DayTimings)> {
+    let cells: Vec<&str> = line
+        .strip_prefix('|')?
+        .strip_suffix('|')?
+        .split('|')
+        .map(str::trim)
+        .collect();
+    let [link, part_one, part_two, _] = cells[..] else {
+        return None;
+    };
+
+    let (year, day) = link
+        .split_once("](./src/days/y")?
+        .1
+        .strip_suffix(".rs)")?
+        .split_once("/day")?;
+
+    Some((
+        (year.parse().ok()?, day.parse().ok()?),
+        [parse_cell(part_one)?, parse_cell(part_two)?],
+    ))
+}
+
+/// Reads the timings of the table between the markers in `contents`. Rows that were edited by hand and can't be read are skipped.
+pub fn parse_table(contents: &str) -> Timings {
+    table_range(contents)
+        .map(|(start, end)| {
+            contents[start..end]
+                .lines()
+                .filter_map(|line| parse_row(line.trim()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Updates `timings` with the parts in `reports`. Days and parts that are not in `reports` keep their timings.
+pub fn merge(timings: &mut Timings, reports: &[PartReport]) {
+    for report in reports {
+        let elapsed =
+            matches!(report.result.value, SolveOutcome::Solved(_)).then_some(report.result.elapsed);
+        let parts = timings.entry((report.year, report.day)).or_default();
+        parts[usize::from(report.part) - 1] = elapsed;
+    }
+}
+
+/// Renders a Markdown table with the timings of every day that has a solved part.
+pub fn render_table(timings: &Timings) -> String {
+    let mut table = String::new();
+    table.push_str("## Benchmarks\n\n");
+    table.push_str("| Day | Part 1 | Part 2 | Total |\n");
+    table.push_str("| :---: | :---: | :---: | :---: |\n");
+
+    let mut total = Duration::ZERO;
+
+    for (&(year, day), &[part_one, part_two]) in timings {
+        if part_one.is_none() && part_two.is_none() {
+            continue;
+        }
+
+        let day_total: Duration = part_one.into_iter().chain(part_two).sum();
+        total += day_total;
+
+        writeln!(
+            table,
+            "| [Day {}](./src/days/y{}/day{:02}.rs) | {} | {} | `{:.2?}` |",
+            day,
+            year,
+            day,
+            format_elapsed(part_one),
+            format_elapsed(part_two),
+            day_total
+        )
+        .unwrap();
+    }
+
+    writeln!(table, "\n**Total: {:.2?}**", total).unwrap();
+    table
+}
+
+/// Returns the start and end of the section between the table markers in `contents`, including the markers.
+fn table_range(contents: &str) -> Option<(usize, usize)> {
+    let start = contents.find(TABLE_START)?;
+    let end = contents[start..].find(TABLE_END)?;
+    Some((start, start + end + TABLE_END.len()))
+}
+
+/// Replaces the section between the table markers in `contents` with `table`, which should end with a newline.
+/// If the markers are missing, they are appended to the end of `contents`.
+pub fn replace_table(contents: &str, table: &str) -> String {
+    let section = format!("{}\n\n{}{}", TABLE_START, table, TABLE_END);
+
+    match table_range(contents) {
+        Some((start, end)) => {
+            format!("{}{}{}", &contents[..start], section, &contents[end..])
+        }
+        None => {
+            let separator = if contents.ends_with('\n') {
+                "\n"
+            } else {
+                "\n\n"
+            };
+            format!("{}{}{}\n", contents, separator, section)
+        }
+    }
+}
+
+/// Writes the timings in `reports` into the table of the readme at `path`, keeping the timings of other days and parts.
+pub fn update(path: &Path, reports: &[PartReport]) -> io::Result<()> {
+    let contents = fs::read_to_string(path)?;
+    let mut timings = parse_table(&contents);
+    merge(&mut timings, reports);
+    fs::write(path, replace_table(&contents, &render_table(&timings)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Answer, Timed};
+
+    fn report(day: u8, part: u8, micros: u64) -> PartReport {
+        PartReport {
+            year: 2022,
+            day,
+            part,
+            result: Timed {
+                value: SolveOutcome::Solved(Answer::Integer(1)),
+                elapsed: Duration::from_micros(micros),
+            },
+            expected: None,
+        }
+    }
+
+    #[test]
+    fn test_render_table() {
+        let reports = [
+            report(1, 1, 10),
+            report(1, 2, 20),
+            PartReport::unsolved(2022, 2, 1),
+            PartReport::unsolved(2022, 2, 2),
+            report(3, 1, 1500),
+            PartReport::unsolved(2022, 3, 2),
+        ];
+
+        let mut timings = Timings::new();
+        merge(&mut timings, &reports);
+
+        assert_eq!(
+            render_table(&timings),
+            "## Benchmarks\n\n\
+             | Day | Part 1 | Part 2 | Total |\n\
+             | :---: | :---: | :---: | :---: |\n\
+             | [Day 1](./src/days/y2022/day01.rs) | `10.00µs` | `20.00µs` | `30.00µs` |\n\
+             | [Day 3](./src/days/y2022/day03.rs) | `1.50ms` | - | `1.50ms` |\n\
+             \n**Total: 1.53ms**\n"
+        );
+    }
+
+    #[test]
+    fn test_replace_table() {
+        let readme = format!(
+            "# Title\n\n{}\nold\n{}\n\n## Usage\n",
+            TABLE_START, TABLE_END
+        );
+
+        let updated = replace_table(&readme, "new\n");
+        assert_eq!(
+            updated,
+            format!(
+                "# Title\n\n{}\n\nnew\n{}\n\n## Usage\n",
+                TABLE_START, TABLE_END
+            )
+        );
+        assert_eq!(replace_table(&updated, "new\n"), updated);
+
+        let appended = replace_table("# Title\n", "new\n");
+        assert_eq!(
+            appended,
+            format!("# Title\n\n{}\n\nnew\n{}\n", TABLE_START, TABLE_END)
+        );
+        assert_eq!(replace_table(&appended, "new\n"), appended);
+    }
+    #[test]
+    fn test_update_subset() {
+        let previous = Timings::from([
+            ((2022, 1), [Some(Duration::from_micros(10)), None]),
+            (
+                (2022, 3),
+                [
+                    Some(Duration::from_micros(1500)),
+                    Some(Duration::from_micros(29_450)),
+                ],
+            ),
+        ]);
+        let readme = replace_table("# Title\n", &render_table(&previous));
+        assert_eq!(parse_table(&readme), previous);
+
+        // a run of `--days 3 --part 2` only replaces the timing of that part.
+        let mut timings = parse_table(&readme);
+        merge(&mut timings, &[report(3, 2, 200)]);
+
+        assert_eq!(
+            render_table(&timings),
+            "## Benchmarks\n\n\
+             | Day | Part 1 | Part 2 | Total |\n\
+             | :---: | :---: | :---: | :---: |\n\
+             | [Day 1](./src/days/y2022/day01.rs) | `10.00µs` | - | `10.00µs` |\n\
+             | [Day 3](./src/days/y2022/day03.rs) | `1.50ms` | `200.00µs` | `1.70ms` |\n\
+             \n**Total: 1.71ms**\n"
+        );
+
+        merge(&mut timings, &[PartReport::unsolved(2022, 1, 1)]);
+        assert!(!render_table(&timings).contains("Day 1]"));
+        assert_eq!(parse_table("# Title\n"), Timings::new());
+    }
+}
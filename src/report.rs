@@ -0,0 +1,351 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    fmt::{self, Display},
+    io::{self, Write},
+    str::FromStr,
+    time::Duration,
+};
+
+use crate::{verify::Verdict, Answer, SolveOutcome, Timed};
+
+/// Output format of the runner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Human readable, ANSI decorated output.
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "unknown format \"{}\", expected one of: text, json, csv",
+                s
+            )),
+        }
+    }
+}
+
+/// The timed outcome of a single day and part.
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub year: i16,
+    pub day: u8,
+    pub part: u8,
+    pub result: Timed<SolveOutcome>,
+    /// The recorded answer this part is checked against.
+    pub expected: Option<Answer>,
+}
+
+impl PartReport {
+    /// A report for a part that could not be run, e.g. because its input is missing.
+    pub fn unsolved(year: i16, day: u8, part: u8) -> Self {
+        PartReport {
+            year,
+            day,
+            part,
+            result: Timed {
+                value: SolveOutcome::NotImplemented,
+                elapsed: Duration::ZERO,
+            },
+            expected: None,
+        }
+    }
+
+    pub fn status(&self) -> Status {
+        match self.result.value {
+            SolveOutcome::Solved(_) => Status::Solved,
+            SolveOutcome::NotImplemented => Status::Unsolved,
+            SolveOutcome::Failed(_) => Status::Failed,
+            SolveOutcome::Panicked(_) => Status::Panicked,
+            SolveOutcome::TimedOut(_) => Status::TimedOut,
+        }
+    }
+
+    pub fn verdict(&self) -> Verdict {
+        Verdict::check(&self.result.value, self.expected.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    Unsolved,
+    Failed,
+    Panicked,
+    TimedOut,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::Failed => "failed",
+            Status::Panicked => "panicked",
+            Status::TimedOut => "timed_out",
+        };
+        write!(f, "{}", status)
+    }
+}
+
+/// Sum of the execution times of all `reports`.
+pub fn total_elapsed(reports: &[PartReport]) -> Duration {
+    reports.iter().map(|report| report.result.elapsed).sum()
+}
+
+/// Writes `reports` as a single JSON object.
+///
+/// Integer answers are emitted as JSON numbers, all other answers as strings.
+/// Parts that failed, panicked or timed out carry their error message in `error`.
+pub fn write_json(out: &mut impl Write, reports: &[PartReport]) -> io::Result<()> {
+    writeln!(out, "{{")?;
+    writeln!(out, "  \"results\": [")?;
+
+    for (i, report) in reports.iter().enumerate() {
+        let answer = json_answer(report.result.value.answer());
+        let expected = json_answer(report.expected.as_ref());
+        let error = match report.result.value.error() {
+            Some(error) => json_string(&error),
+            None => "null".into(),
+        };
+        let separator = if i + 1 < reports.len() { "," } else { "" };
+
+        writeln!(
+            out,
+            "    {{\"year\": {}, \"day\": {}, \"part\": {}, \"status\": \"{}\", \"answer\": {}, \"error\": {}, \"verdict\": \"{}\", \"expected\": {}, \"elapsed_ns\": {}}}{}",
+            report.year,
+            report.day,
+            report.part,
+            report.status(),
+            answer,
+            error,
+            report.verdict(),
+            expected,
+            report.result.elapsed.as_nanos(),
+            separator
+        )?;
+    }
+
+    writeln!(out, "  ],")?;
+    writeln!(
+        out,
+        "  \"total_elapsed_ns\": {}",
+        total_elapsed(reports).as_nanos()
+    )?;
+    writeln!(out, "}}")
+}
+
+/// Writes `reports` as CSV with a header row.
+pub fn write_csv(out: &mut impl Write, reports: &[PartReport]) -> io::Result<()> {
+    writeln!(
+        out,
+        "year,day,part,status,answer,error,verdict,expected,elapsed_ns"
+    )?;
+
+    for report in reports {
+        let answer = report
+            .result
+            .value
+            .answer()
+            .map(|answer| csv_field(&answer.to_string()))
+            .unwrap_or_default();
+        let error = report
+            .result
+            .value
+            .error()
+            .map(|error| csv_field(&error))
+            .unwrap_or_default();
+        let expected = report
+            .expected
+            .as_ref()
+            .map(|answer| csv_field(&answer.to_string()))
+            .unwrap_or_default();
+
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{},{},{}",
+            report.year,
+            report.day,
+            report.part,
+            report.status(),
+            answer,
+            error,
+            report.verdict(),
+            expected,
+            report.result.elapsed.as_nanos()
+        )?;
+    }
+
+    Ok(())
+}
+
+fn json_answer(answer: Option<&Answer>) -> String {
+    match answer {
+        Some(Answer::Integer(value)) => value.to_string(),
+        Some(Answer::SignedInteger(value)) => value.to_string(),
+        Some(answer) => json_string(&answer.to_string()),
+        None => "null".into(),
+    }
+}
+
+pub(crate) fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+
+    for ch in value.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            ch if ch.is_control() => escaped.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => escaped.push(ch),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reports() -> Vec<PartReport> {
+        vec![
+            PartReport {
+                year: 2022,
+                day: 5,
+                part: 1,
+                result: Timed {
+                    value: SolveOutcome::Solved(Answer::Text("CMZ".into())),
+                    elapsed: Duration::from_nanos(1500),
+                },
+                expected: Some(Answer::Text("CMZ".into())),
+            },
+            PartReport {
+                year: 2022,
+                day: 10,
+                part: 2,
+                result: Timed {
+                    value: SolveOutcome::Solved(Answer::ascii_art(["#.", ".#"])),
+                    elapsed: Duration::from_nanos(20),
+                },
+                expected: None,
+            },
+            PartReport {
+                year: 2022,
+                day: 11,
+                part: 1,
+                result: Timed {
+                    value: SolveOutcome::Solved(Answer::Integer(10605)),
+                    elapsed: Duration::from_micros(3),
+                },
+                expected: Some(Answer::Integer(10604)),
+            },
+            PartReport {
+                year: 2022,
+                day: 13,
+                part: 2,
+                result: Timed {
+                    value: SolveOutcome::Failed("unexpected \"x\", at 1,2".into()),
+                    elapsed: Duration::from_nanos(7),
+                },
+                expected: None,
+            },
+            PartReport::unsolved(2022, 14, 1),
+        ]
+    }
+
+    #[test]
+    fn test_write_json() {
+        let mut output = Vec::new();
+        write_json(&mut output, &reports()).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            r##"{
+  "results": [
+    {"year": 2022, "day": 5, "part": 1, "status": "solved", "answer": "CMZ", "error": null, "verdict": "correct", "expected": "CMZ", "elapsed_ns": 1500},
+    {"year": 2022, "day": 10, "part": 2, "status": "solved", "answer": "#.\n.#", "error": null, "verdict": "unknown", "expected": null, "elapsed_ns": 20},
+    {"year": 2022, "day": 11, "part": 1, "status": "solved", "answer": 10605, "error": null, "verdict": "wrong", "expected": 10604, "elapsed_ns": 3000},
+    {"year": 2022, "day": 13, "part": 2, "status": "failed", "answer": null, "error": "unexpected \"x\", at 1,2", "verdict": "unknown", "expected": null, "elapsed_ns": 7},
+    {"year": 2022, "day": 14, "part": 1, "status": "unsolved", "answer": null, "error": null, "verdict": "unknown", "expected": null, "elapsed_ns": 0}
+  ],
+  "total_elapsed_ns": 4527
+}
+"##
+        );
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let mut output = Vec::new();
+        write_csv(&mut output, &reports()).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "year,day,part,status,answer,error,verdict,expected,elapsed_ns\n\
+             2022,5,1,solved,CMZ,,correct,CMZ,1500\n\
+             2022,10,2,solved,\"#.\n.#\",,unknown,,20\n\
+             2022,11,1,solved,10605,,wrong,10604,3000\n\
+             2022,13,2,failed,,\"unexpected \"\"x\"\", at 1,2\",unknown,,7\n\
+             2022,14,1,unsolved,,,unknown,,0\n"
+        );
+    }
+
+    #[test]
+    fn test_status() {
+        let report = |value| PartReport {
+            year: 2022,
+            day: 11,
+            part: 2,
+            result: Timed {
+                value,
+                elapsed: Duration::from_secs(5),
+            },
+            expected: None,
+        };
+
+        let panicked = report(SolveOutcome::Panicked(
+            "attempt to multiply with overflow".into(),
+        ));
+        assert_eq!(panicked.status().to_string(), "panicked");
+
+        let timed_out = report(SolveOutcome::TimedOut(Duration::from_secs(5)));
+        assert_eq!(timed_out.status().to_string(), "timed_out");
+
+        let mut output = Vec::new();
+        write_csv(&mut output, &[panicked, timed_out]).unwrap();
+        assert!(String::from_utf8(output).unwrap().ends_with(
+            "2022,11,2,panicked,,attempt to multiply with overflow,unknown,,5000000000\n\
+             2022,11,2,timed_out,,timed out after 5s,unknown,,5000000000\n"
+        ));
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("json".parse::<Format>(), Ok(Format::Json));
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
@@ -0,0 +1,307 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+    },
+    thread,
+    time::Duration,
+};
+
+use crate::{solve_part, Solution, SolveOutcome, Timed};
+
+/// A set of days parsed from a list like `1,5,10-13`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayList(pub BTreeSet<u8>);
+
+impl FromStr for DayList {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |value: &str| match value.trim().parse::<u8>() {
+            Ok(day) if (1..=25).contains(&day) => Ok(day),
+            _ => Err(format!("invalid day \"{}\", expected 1-25", value.trim())),
+        };
+
+        let mut days = BTreeSet::new();
+
+        for item in s.split(',').filter(|item| !item.trim().is_empty()) {
+            match item.split_once('-') {
+                Some((from, to)) => {
+                    let (from, to) = (parse_day(from)?, parse_day(to)?);
+                    if from > to {
+                        return Err(format!("invalid range \"{}\"", item.trim()));
+                    }
+                    days.extend(from..=to);
+                }
+                None => {
+                    days.insert(parse_day(item)?);
+                }
+            }
+        }
+
+        if days.is_empty() {
+            return Err("expected at least one day".into());
+        }
+
+        Ok(DayList(days))
+    }
+}
+
+/// Which days and parts the runner should run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Selection {
+    /// `None` selects every day.
+    pub days: Option<DayList>,
+    /// `None` selects both parts.
+    pub part: Option<u8>,
+}
+
+impl Selection {
+    pub fn includes_day(&self, day: u8) -> bool {
+        self.days.as_ref().is_none_or(|days| days.0.contains(&day))
+    }
+
+    pub fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+}
+
+/// Parses a part number, accepting only `1` and `2`.
+pub fn parse_part(s: &str) -> Result<u8, String> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("invalid part \"{}\", expected 1 or 2", s)),
+    }
+}
+
+/// Parses a time limit in (fractional) seconds, e.g. `2.5`.
+pub fn parse_timeout(s: &str) -> Result<Duration, String> {
+    match s.parse::<f64>().map(Duration::try_from_secs_f64) {
+        Ok(Ok(timeout)) if !timeout.is_zero() => Ok(timeout),
+        _ => Err(format!(
+            "invalid timeout \"{}\", expected a positive number of seconds",
+            s
+        )),
+    }
+}
+
+/// Maps `items` with `func` on up to `jobs` threads and passes the results to `consume`
+/// in the order of `items`, as soon as all previous results are available.
+pub fn map_in_order<T, R, F, C>(items: &[T], jobs: usize, func: F, mut consume: C)
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+    C: FnMut(R),
+{
+    if jobs <= 1 {
+        items.iter().map(&func).for_each(consume);
+        return;
+    }
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            let sender = sender.clone();
+            let (next, func) = (&next, &func);
+
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::SeqCst);
+                match items.get(i) {
+                    Some(item) => {
+                        if sender.send((i, func(item))).is_err() {
+                            break;
+                        }
+                    }
+                    None => break,
+                }
+            });
+        }
+        drop(sender);
+
+        // buffer results that finished early until it's their turn.
+        let mut pending = BTreeMap::new();
+        let mut expected = 0;
+
+        for (i, result) in receiver {
+            pending.insert(i, result);
+            while let Some(result) = pending.remove(&expected) {
+                consume(result);
+                expected += 1;
+            }
+        }
+    });
+}
+
+/// Runs `part` of `solution` against `input` like [`solve_part`], but gives up once `timeout` has passed.
+///
+/// The part runs on its own thread, which can't be stopped: a part that timed out keeps running
+/// in the background until the process exits.
+pub fn solve_part_within(
+    solution: &'static dyn Solution,
+    part: u8,
+    input: &str,
+    timeout: Option<Duration>,
+) -> Timed<SolveOutcome> {
+    let timeout = match timeout {
+        Some(timeout) => timeout,
+        None => return solve_part(solution, part, input),
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    let input = input.to_owned();
+    let spawned = thread::Builder::new()
+        .name(format!("day {:02} part {}", solution.day(), part))
+        .spawn(move || {
+            let _ = sender.send(solve_part(solution, part, &input));
+        });
+
+    if let Err(e) = spawned {
+        return Timed {
+            value: SolveOutcome::Failed(format!("could not start thread: {}", e)),
+            elapsed: Duration::ZERO,
+        };
+    }
+
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(RecvTimeoutError::Timeout) => Timed {
+            value: SolveOutcome::TimedOut(timeout),
+            elapsed: timeout,
+        },
+        // `solve_part` catches panics, so this only happens if the thread was torn down otherwise.
+        Err(RecvTimeoutError::Disconnected) => Timed {
+            value: SolveOutcome::Panicked("the part's thread stopped unexpectedly".into()),
+            elapsed: Duration::ZERO,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_parse_day_list() {
+        assert_eq!(
+            "1,5,10-13".parse::<DayList>().unwrap().0,
+            BTreeSet::from([1, 5, 10, 11, 12, 13])
+        );
+        assert_eq!(
+            "3, 2-3".parse::<DayList>().unwrap().0,
+            BTreeSet::from([2, 3])
+        );
+        assert!("0".parse::<DayList>().is_err());
+        assert!("26".parse::<DayList>().is_err());
+        assert!("5-1".parse::<DayList>().is_err());
+        assert!("a".parse::<DayList>().is_err());
+        assert!("".parse::<DayList>().is_err());
+    }
+
+    #[test]
+    fn test_selection() {
+        let selection = Selection {
+            days: Some("2-3".parse().unwrap()),
+            part: Some(2),
+        };
+        assert!(selection.includes_day(3));
+        assert!(!selection.includes_day(4));
+        assert_eq!(selection.parts(), vec![2]);
+
+        let all = Selection::default();
+        assert!(all.includes_day(25));
+        assert_eq!(all.parts(), vec![1, 2]);
+
+        assert_eq!(parse_part("1"), Ok(1));
+        assert!(parse_part("3").is_err());
+
+        assert_eq!(parse_timeout("2.5"), Ok(Duration::from_millis(2500)));
+        assert!(parse_timeout("0").is_err());
+        assert!(parse_timeout("soon").is_err());
+        assert!(parse_timeout("1e30").is_err());
+        assert!(parse_timeout("NaN").is_err());
+    }
+
+    #[test]
+    fn test_map_in_order() {
+        let items: Vec<u64> = (0..8).collect();
+
+        for jobs in [1, 3] {
+            let mut results = Vec::new();
+            map_in_order(
+                &items,
+                jobs,
+                |&i| {
+                    // finish later items first.
+                    thread::sleep(Duration::from_millis(8 - i));
+                    i * 10
+                },
+                |result| results.push(result),
+            );
+            assert_eq!(results, vec![0, 10, 20, 30, 40, 50, 60, 70]);
+        }
+    }
+
+    #[test]
+    fn test_solve_part_within() {
+        struct Sleeps;
+
+        impl Solution for Sleeps {
+            fn year(&self) -> i16 {
+                crate::DEFAULT_YEAR
+            }
+
+            fn day(&self) -> u8 {
+                11
+            }
+
+            fn title(&self) -> &'static str {
+                "Sleeps"
+            }
+
+            fn part_one(&self, _: &str) -> SolveOutcome {
+                thread::sleep(Duration::from_secs(5));
+                SolveOutcome::NotImplemented
+            }
+
+            fn part_two(&self, _: &str) -> SolveOutcome {
+                panic!("too slow")
+            }
+        }
+
+        let limit = Duration::from_millis(20);
+        assert_eq!(
+            solve_part_within(&Sleeps, 1, "", Some(limit)),
+            Timed {
+                value: SolveOutcome::TimedOut(limit),
+                elapsed: limit,
+            }
+        );
+        assert_eq!(
+            solve_part_within(&Sleeps, 2, "", Some(Duration::from_secs(5))).value,
+            SolveOutcome::Panicked("too slow".into())
+        );
+
+        let day01 = days::get(2022, 1).unwrap();
+        let input = crate::read_file("examples", 2022, 1).unwrap();
+        assert_eq!(
+            solve_part_within(day01, 1, &input, Some(Duration::from_secs(5))).value,
+            solve_part(day01, 1, &input).value
+        );
+    }
+}
@@ -0,0 +1,233 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+/// Parses a day number, accepting only puzzle days `1..=25`.
+pub fn parse_day(s: &str) -> Result<u8, String> {
+    match s.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("invalid day \"{}\", expected 1-25", s)),
+    }
+}
+
+/// Adds `pub mod dayNN;` and `&dayNN::DayNN,` to the source of a year's registry `src/days/yYYYY/mod.rs`,
+/// keeping both lists ordered by day. Returns `None` if the day is already registered.
+pub fn register_day(source: &str, day: u8) -> Result<Option<String>, String> {
+    let module = format!("pub mod day{:02};", day);
+    let entry = format!("    &day{:02}::Day{:02},", day, day);
+
+    insert_sorted(source, [(module, "pub mod day"), (entry, "    &day")])
+}
+
+/// Adds `pub mod yYYYY;` and `yYYYY::ALL,` to the source of `src/days/mod.rs`, keeping both lists ordered by year.
+/// Returns `None` if the year is already registered.
+pub fn register_year(source: &str, year: i16) -> Result<Option<String>, String> {
+    let module = format!("pub mod y{};", year);
+    let entry = format!("    y{}::ALL,", year);
+
+    insert_sorted(source, [(module, "pub mod y"), (entry, "    y")])
+}
+
+/// Inserts every line into the list of lines starting with its prefix, unless it is already there.
+fn insert_sorted(source: &str, insertions: [(String, &str); 2]) -> Result<Option<String>, String> {
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+    let mut changed = false;
+
+    for (line, prefix) in insertions {
+        if lines.contains(&line) {
+            continue;
+        }
+
+        let positions: Vec<usize> = (0..lines.len())
+            .filter(|&i| lines[i].starts_with(prefix))
+            .collect();
+        let last = positions.last().ok_or_else(|| {
+            format!(
+                "could not find where to add `{}`, expected lines starting with `{}`",
+                line.trim(),
+                prefix.trim()
+            )
+        })?;
+
+        // the lines of a list only differ in the day or year, so they sort like them.
+        let at = positions
+            .iter()
+            .copied()
+            .find(|&i| lines[i] > line)
+            .unwrap_or(last + 1);
+
+        lines.insert(at, line);
+        changed = true;
+    }
+
+    Ok(changed.then(|| lines.join("\n") + "\n"))
+}
+
+/// Writes files and restores their previous state on [`Transaction::rollback`].
+#[derive(Debug, Default)]
+pub struct Transaction {
+    /// Written paths with their previous contents, `None` if the file did not exist.
+    written: Vec<(PathBuf, Option<Vec<u8>>)>,
+    /// Directories created for the written files, outermost first.
+    created_dirs: Vec<PathBuf>,
+}
+
+impl Transaction {
+    pub fn write(&mut self, path: &Path, contents: &str) -> io::Result<()> {
+        let previous = match fs::read(path) {
+            Ok(previous) => Some(previous),
+            Err(e) if e.kind() == ErrorKind::NotFound => None,
+            Err(e) => return Err(e),
+        };
+
+        // record before writing, a failed write may still have created or truncated the file.
+        self.written.push((path.to_owned(), previous));
+        if let Some(dir) = path.parent() {
+            self.create_dirs(dir)?;
+        }
+        fs::write(path, contents)
+    }
+
+    /// Creates `dir` and its missing parents, and records the ones that did not exist.
+    fn create_dirs(&mut self, dir: &Path) -> io::Result<()> {
+        let mut missing: Vec<PathBuf> = dir
+            .ancestors()
+            .take_while(|dir| !dir.as_os_str().is_empty() && !dir.exists())
+            .map(Path::to_path_buf)
+            .collect();
+        missing.reverse();
+
+        for dir in missing {
+            match fs::create_dir(&dir) {
+                Ok(_) => self.created_dirs.push(dir),
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+
+    /// Undoes all writes, most recent first, and removes the directories created for them.
+    pub fn rollback(self) -> io::Result<()> {
+        for (path, previous) in self.written.into_iter().rev() {
+            match previous {
+                Some(previous) => fs::write(&path, previous)?,
+                None => match fs::remove_file(&path) {
+                    Err(e) if e.kind() != ErrorKind::NotFound => return Err(e),
+                    _ => {}
+                },
+            }
+        }
+        for dir in self.created_dirs.into_iter().rev() {
+            match fs::remove_dir(&dir) {
+                Err(e) if e.kind() != ErrorKind::NotFound => return Err(e),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp::TempPath;
+
+    const REGISTRY: &str = "use crate::Solution;
+
+pub mod day01;
+pub mod day03;
+
+pub static ALL: &[&dyn Solution] = &[
+    &day01::Day01,
+    &day03::Day03,
+];
+";
+
+    #[test]
+    fn test_parse_day() {
+        assert_eq!(parse_day("7"), Ok(7));
+        assert_eq!(parse_day("25"), Ok(25));
+        assert!(parse_day("0").is_err());
+        assert!(parse_day("99").is_err());
+        assert!(parse_day("x").is_err());
+    }
+
+    #[test]
+    fn test_register_day() {
+        let registered = register_day(REGISTRY, 2).unwrap().unwrap();
+        assert_eq!(
+            registered,
+            "use crate::Solution;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+
+pub static ALL: &[&dyn Solution] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+];
+"
+        );
+        assert_eq!(register_day(&registered, 2), Ok(None));
+
+        let appended = register_day(REGISTRY, 12).unwrap().unwrap();
+        assert!(appended.contains("pub mod day03;\npub mod day12;\n"));
+        assert!(appended.contains("    &day03::Day03,\n    &day12::Day12,\n];"));
+
+        assert!(register_day("", 1).is_err());
+    }
+
+    #[test]
+    fn test_register_year() {
+        let registry = "pub mod y2022;
+
+pub static YEARS: &[&[&dyn Solution]] = &[
+    y2022::ALL,
+];
+";
+        let registered = register_year(registry, 2021).unwrap().unwrap();
+        assert_eq!(
+            registered,
+            "pub mod y2021;
+pub mod y2022;
+
+pub static YEARS: &[&[&dyn Solution]] = &[
+    y2021::ALL,
+    y2022::ALL,
+];
+"
+        );
+        assert_eq!(register_year(&registered, 2022), Ok(None));
+    }
+
+    #[test]
+    fn test_rollback() {
+        let dir = TempPath::new("scaffold");
+        fs::create_dir_all(&dir).unwrap();
+        let (existing, created) = (dir.join("existing.txt"), dir.join("created.txt"));
+        let nested = dir.join("y2023").join("day01");
+        fs::write(&existing, "before").unwrap();
+
+        let mut transaction = Transaction::default();
+        transaction.write(&existing, "after").unwrap();
+        transaction.write(&created, "new").unwrap();
+        transaction.write(&nested.join("mod.rs"), "new").unwrap();
+        transaction.write(&nested.join("01.rs"), "new").unwrap();
+        assert_eq!(fs::read_to_string(&existing).unwrap(), "after");
+
+        transaction.rollback().unwrap();
+        assert_eq!(fs::read_to_string(&existing).unwrap(), "before");
+        assert!(!created.exists());
+        assert!(!dir.join("y2023").exists());
+        assert!(dir.exists());
+    }
+}
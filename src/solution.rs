@@ -0,0 +1,64 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::SolveOutcome;
+
+/// A single day's puzzle solution that can be called in-process.
+///
+/// Implement this with the [`solution!`](crate::solution) macro rather than by hand.
+pub trait Solution: Sync {
+    /// Year of the puzzle, e.g. `2022`.
+    fn year(&self) -> i16;
+
+    /// Day of the puzzle (`1..=25`).
+    fn day(&self) -> u8;
+
+    /// Title of the puzzle, as shown on the puzzle page.
+    fn title(&self) -> &'static str;
+
+    fn part_one(&self, input: &str) -> SolveOutcome;
+
+    fn part_two(&self, input: &str) -> SolveOutcome;
+
+    /// Runs `part` (`1` or `2`) against `input`.
+    fn solve(&self, part: u8, input: &str) -> SolveOutcome {
+        match part {
+            1 => self.part_one(input),
+            2 => self.part_two(input),
+            _ => panic!("unknown part {}, expected 1 or 2", part),
+        }
+    }
+}
+
+/// Declares a unit struct implementing [`Solution`] for the `part_one` and `part_two` functions in scope.
+///
+/// Example: `advent_of_code::solution!(Day01, 2022, 1, "Calorie Counting");`
+#[macro_export]
+macro_rules! solution {
+    ($name:ident, $year:expr, $day:expr, $title:expr) => {
+        pub struct $name;
+
+        impl $crate::Solution for $name {
+            fn year(&self) -> i16 {
+                $year
+            }
+
+            fn day(&self) -> u8 {
+                $day
+            }
+
+            fn title(&self) -> &'static str {
+                $title
+            }
+
+            fn part_one(&self, input: &str) -> $crate::SolveOutcome {
+                part_one(input).into()
+            }
+
+            fn part_two(&self, input: &str) -> $crate::SolveOutcome {
+                part_two(input).into()
+            }
+        }
+    };
+}
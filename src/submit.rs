@@ -0,0 +1,458 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    fs,
+    io::{self, ErrorKind},
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    client::{Client, ClientError},
+    puzzle, read_optional, year_path,
+};
+
+/// The verdict of the server on a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Response {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// Submitted too recently, nothing was checked.
+    Wait(Duration),
+    /// The part was solved before, nothing was checked.
+    AlreadySolved,
+    /// A response this parser does not know, with its text.
+    Unknown(String),
+}
+
+impl Response {
+    /// Reads the verdict from the HTML the server responds with.
+    pub fn parse(html: &str) -> Self {
+        let text = puzzle::articles(html)
+            .first()
+            .map(|article| puzzle::to_markdown(article))
+            .unwrap_or_else(|| html.to_owned());
+
+        if text.contains("That's the right answer") {
+            Response::Correct
+        } else if text.contains("your answer is too high") {
+            Response::TooHigh
+        } else if text.contains("your answer is too low") {
+            Response::TooLow
+        } else if text.contains("That's not the right answer") {
+            Response::Wrong
+        } else if text.contains("You gave an answer too recently") {
+            Response::Wait(parse_wait(&text).unwrap_or(Duration::from_secs(60)))
+        } else if text.contains("Did you already complete it?") {
+            Response::AlreadySolved
+        } else {
+            Response::Unknown(text.trim().to_owned())
+        }
+    }
+
+    /// Name used in the attempts file for verdicts on wrong answers.
+    fn key(&self) -> Option<&'static str> {
+        match self {
+            Response::TooHigh => Some("too_high"),
+            Response::TooLow => Some("too_low"),
+            Response::Wrong => Some("wrong"),
+            _ => None,
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "too_high" => Some(Response::TooHigh),
+            "too_low" => Some(Response::TooLow),
+            "wrong" => Some(Response::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Response {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Response::Correct => write!(f, "correct"),
+            Response::TooHigh => write!(f, "wrong, too high"),
+            Response::TooLow => write!(f, "wrong, too low"),
+            Response::Wrong => write!(f, "wrong"),
+            Response::Wait(duration) => write!(f, "too many submissions, wait {:?}", duration),
+            Response::AlreadySolved => write!(f, "already solved"),
+            Response::Unknown(text) => write!(f, "unknown response: {}", text),
+        }
+    }
+}
+
+/// Parses `You have 4m 32s left to wait.`
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = text[start..].find(" left to wait")?;
+
+    text[start..start + end]
+        .split_whitespace()
+        .map(|part| {
+            let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// A wrong answer that was submitted before.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub part: u8,
+    pub answer: String,
+    pub response: Response,
+}
+
+/// Wrong answers of a day and the end of the cooldown after answering too recently,
+/// stored in `src/answers/<year>/NN.attempts` as `<part> <verdict> <answer>` lines
+/// and a `wait_until <unix seconds>` line.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Attempts {
+    pub wrong: Vec<Attempt>,
+    /// The server does not check answers before this time.
+    pub wait_until: Option<SystemTime>,
+}
+
+impl Attempts {
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let mut attempts = Attempts::default();
+
+        for (i, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(seconds) = line.strip_prefix("wait_until ") {
+                let seconds = seconds
+                    .parse()
+                    .map_err(|_| format!("line {}: expected `wait_until <unix seconds>`", i + 1))?;
+                attempts.wait_until = Some(UNIX_EPOCH + Duration::from_secs(seconds));
+                continue;
+            }
+
+            let mut fields = line.splitn(3, ' ');
+            let part = fields.next().and_then(|part| part.parse().ok());
+            let response = fields.next().and_then(Response::from_key);
+
+            match (part, response, fields.next()) {
+                (Some(part), Some(response), Some(answer)) => attempts.wrong.push(Attempt {
+                    part,
+                    answer: answer.to_owned(),
+                    response,
+                }),
+                _ => {
+                    return Err(format!(
+                        "line {}: expected `<part> <too_high|too_low|wrong> <answer>`",
+                        i + 1
+                    ))
+                }
+            }
+        }
+
+        Ok(attempts)
+    }
+
+    /// Returns how long the server still refuses to check answers at `now`, if it does.
+    pub fn cooldown(&self, now: SystemTime) -> Option<Duration> {
+        self.wait_until
+            .and_then(|wait_until| wait_until.duration_since(now).ok())
+            .filter(|left| !left.is_zero())
+    }
+
+    /// Returns why `answer` is known to be wrong for `part`, if it is.
+    pub fn reject(&self, part: u8, answer: &str) -> Option<String> {
+        let attempts = self.wrong.iter().filter(|attempt| attempt.part == part);
+        let value = answer.parse::<i128>().ok();
+
+        for attempt in attempts {
+            if attempt.answer == answer {
+                return Some(format!(
+                    "{} was already submitted and is {}",
+                    answer, attempt.response
+                ));
+            }
+
+            let bound = match attempt.answer.parse::<i128>() {
+                Ok(bound) => bound,
+                Err(_) => continue,
+            };
+            match (value, &attempt.response) {
+                (Some(value), Response::TooHigh) if value >= bound => {
+                    return Some(format!(
+                        "{} is not below {}, which is too high",
+                        answer, bound
+                    ))
+                }
+                (Some(value), Response::TooLow) if value <= bound => {
+                    return Some(format!(
+                        "{} is not above {}, which is too low",
+                        answer, bound
+                    ))
+                }
+                _ => {}
+            }
+        }
+
+        None
+    }
+}
+
+impl Display for Attempts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(wait_until) = self.wait_until {
+            let seconds = wait_until
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs();
+            writeln!(f, "wait_until {}", seconds)?;
+        }
+        writeln!(f, "# part verdict answer")?;
+        for attempt in &self.wrong {
+            if let Some(key) = attempt.response.key() {
+                writeln!(f, "{} {} {}", attempt.part, key, attempt.answer)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn attempts_path(year: i16, day: u8) -> PathBuf {
+    year_path("answers", year).join(format!("{:02}.attempts", day))
+}
+
+/// Loads the wrong answers submitted for `day` of `year`. A missing file means there are none.
+pub fn load_attempts(year: i16, day: u8) -> io::Result<Attempts> {
+    let path = attempts_path(year, day);
+
+    match read_optional(&path)? {
+        Some(contents) => Attempts::parse(&contents).map_err(|e| {
+            io::Error::new(
+                ErrorKind::InvalidData,
+                format!("could not parse \"{}\": {}", path.display(), e),
+            )
+        }),
+        None => Ok(Attempts::default()),
+    }
+}
+
+pub fn save_attempts(year: i16, day: u8, attempts: &Attempts) -> io::Result<PathBuf> {
+    let path = attempts_path(year, day);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&path, attempts.to_string())?;
+    Ok(path)
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    /// The answer is known to be wrong and was not sent.
+    Rejected(String),
+    Client(ClientError),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::Rejected(reason) => write!(f, "not submitting, {}", reason),
+            SubmitError::Client(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for SubmitError {}
+
+/// Submits `answer` for `part` unless `attempts` show it is wrong or the server still asks to wait,
+/// and records a wrong answer or the end of the cooldown in `attempts`.
+pub fn submit(
+    client: &Client,
+    year: i16,
+    day: u8,
+    part: u8,
+    answer: &str,
+    attempts: &mut Attempts,
+) -> Result<Response, SubmitError> {
+    if let Some(reason) = attempts.reject(part, answer) {
+        return Err(SubmitError::Rejected(reason));
+    }
+    if let Some(left) = attempts.cooldown(SystemTime::now()) {
+        // round up, so waiting the printed time is always enough.
+        let left = Duration::from_secs(left.as_secs() + u64::from(left.subsec_nanos() > 0));
+        return Err(SubmitError::Rejected(format!(
+            "an answer was given too recently, wait {:?}",
+            left
+        )));
+    }
+
+    let html = client
+        .post(
+            &format!("/{}/day/{}/answer", year, day),
+            &[("level", &part.to_string()), ("answer", answer)],
+        )
+        .map_err(SubmitError::Client)?;
+
+    let response = Response::parse(&html);
+    attempts.wait_until = match response {
+        Response::Wait(duration) => Some(SystemTime::now() + duration),
+        _ => None,
+    };
+    if response.key().is_some() {
+        attempts.wrong.push(Attempt {
+            part,
+            answer: answer.to_owned(),
+            response: response.clone(),
+        });
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::mock, temp::TempPath};
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><main><article><p>{}</p></article></main></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            Response::parse(&page(
+                "That's the right answer!  You are <em>one gold star</em> closer."
+            )),
+            Response::Correct
+        );
+        assert_eq!(
+            Response::parse(&page(
+                "That's not the right answer; your answer is too high.  If you're stuck..."
+            )),
+            Response::TooHigh
+        );
+        assert_eq!(
+            Response::parse(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Response::TooLow
+        );
+        assert_eq!(
+            Response::parse(&page("That's not the right answer.  If you're stuck...")),
+            Response::Wrong
+        );
+        assert_eq!(
+            Response::parse(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 32s left to wait.")),
+            Response::Wait(Duration::from_secs(272))
+        );
+        assert_eq!(
+            Response::parse(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Response::AlreadySolved
+        );
+        assert!(matches!(Response::parse("<p>?</p>"), Response::Unknown(_)));
+    }
+
+    #[test]
+    fn test_attempts() {
+        let attempts =
+            Attempts::parse("# part verdict answer\n1 too_high 100\n1 too_low 10\n2 wrong ABC\n")
+                .unwrap();
+
+        assert_eq!(attempts.reject(1, "50"), None);
+        assert!(attempts.reject(1, "100").is_some());
+        assert!(attempts.reject(1, "150").is_some());
+        assert!(attempts.reject(1, "-3").is_some());
+        assert!(attempts.reject(2, "ABC").is_some());
+        assert_eq!(attempts.reject(2, "ABD"), None);
+        assert_eq!(attempts.reject(2, "150"), None);
+
+        assert_eq!(Attempts::parse(&attempts.to_string()), Ok(attempts));
+        assert!(Attempts::parse("1 maybe 5").is_err());
+        assert!(Attempts::parse("wait_until soon").is_err());
+    }
+
+    #[test]
+    fn test_cooldown() {
+        let attempts = Attempts::parse("wait_until 1000\n1 wrong 5\n").unwrap();
+        let at = |seconds| UNIX_EPOCH + Duration::from_secs(seconds);
+
+        assert_eq!(attempts.wait_until, Some(at(1000)));
+        assert_eq!(attempts.cooldown(at(940)), Some(Duration::from_secs(60)));
+        assert_eq!(attempts.cooldown(at(1000)), None);
+        assert_eq!(attempts.cooldown(at(1100)), None);
+        assert_eq!(Attempts::default().cooldown(at(0)), None);
+
+        assert_eq!(Attempts::parse(&attempts.to_string()), Ok(attempts));
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, requests) = mock::serve(vec![
+            (
+                200,
+                page("That's not the right answer; your answer is too high."),
+            ),
+            (200, page("That's the right answer!")),
+            (
+                200,
+                page("You gave an answer too recently.  You have 1m 0s left to wait."),
+            ),
+        ]);
+        let cache_dir = TempPath::new("submit");
+        let client = Client::new(&base_url, "secret", cache_dir.to_path_buf());
+        let mut attempts = Attempts::default();
+
+        assert_eq!(
+            submit(&client, 2022, 1, 1, "500", &mut attempts).unwrap(),
+            Response::TooHigh
+        );
+        // known to be too high, so nothing is sent.
+        assert!(matches!(
+            submit(&client, 2022, 1, 1, "600", &mut attempts),
+            Err(SubmitError::Rejected(_))
+        ));
+        assert_eq!(
+            submit(&client, 2022, 1, 1, "400", &mut attempts).unwrap(),
+            Response::Correct
+        );
+        assert_eq!(attempts.wrong.len(), 1);
+        assert_eq!(attempts.wait_until, None);
+
+        assert_eq!(
+            submit(&client, 2022, 1, 2, "7", &mut attempts).unwrap(),
+            Response::Wait(Duration::from_secs(60))
+        );
+        // still cooling down, so nothing is sent.
+        assert!(matches!(
+            submit(&client, 2022, 1, 2, "8", &mut attempts),
+            Err(SubmitError::Rejected(reason)) if reason.contains("wait")
+        ));
+        assert!(attempts.cooldown(SystemTime::now()).is_some());
+
+        let request = requests.recv().unwrap();
+        assert_eq!(request.line, "POST /2022/day/1/answer HTTP/1.1");
+        assert_eq!(request.body, "level=1&answer=500");
+        assert_eq!(request.header("cookie"), Some("session=secret"));
+    }
+}
@@ -0,0 +1,48 @@
+//! Temporary files and directories for tests.
+use std::{
+    env, fs,
+    ops::Deref,
+    path::{Path, PathBuf},
+    process,
+};
+
+/// A path in the temp directory that is unique to this test process and removed again on drop,
+/// whether a file or a directory was created at it.
+pub(crate) struct TempPath(PathBuf);
+
+impl TempPath {
+    /// Reserves `aoc-<pid>-<name>` in the temp directory, removing leftovers of an earlier run.
+    pub(crate) fn new(name: &str) -> Self {
+        let path = TempPath(env::temp_dir().join(format!("aoc-{}-{}", process::id(), name)));
+        path.remove();
+        path
+    }
+
+    fn remove(&self) {
+        let _ = if self.0.is_dir() {
+            fs::remove_dir_all(&self.0)
+        } else {
+            fs::remove_file(&self.0)
+        };
+    }
+}
+
+impl Deref for TempPath {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl AsRef<Path> for TempPath {
+    fn as_ref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempPath {
+    fn drop(&mut self) {
+        self.remove();
+    }
+}
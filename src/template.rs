@@ -0,0 +1,66 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{fs, io, path::PathBuf};
+
+use crate::project_path;
+
+/// Returns the path of `templates/<name>` in the project directory.
+pub fn template_path(name: &str) -> PathBuf {
+    project_path("templates").join(name)
+}
+
+pub fn load(name: &str) -> io::Result<String> {
+    fs::read_to_string(template_path(name))
+}
+
+/// Replaces every `{{key}}` placeholder in `template` with its value from `values`.
+/// Fails on placeholders without a value, so typos in templates do not end up in generated code.
+pub fn render(template: &str, values: &[(&str, &str)]) -> Result<String, String> {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        result.push_str(&rest[..start]);
+
+        let end = rest[start..]
+            .find("}}")
+            .ok_or("unterminated placeholder, expected `}}`")?;
+        let key = rest[start + 2..start + end].trim();
+
+        match values.iter().find(|(k, _)| *k == key) {
+            Some((_, value)) => result.push_str(value),
+            None => return Err(format!("unknown placeholder `{{{{{}}}}}`", key)),
+        }
+
+        rest = &rest[start + end + 2..];
+    }
+
+    result.push_str(rest);
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let values = [("day", "7"), ("day_padded", "07")];
+
+        assert_eq!(
+            render("Day{{day_padded}}, {{ day }}", &values),
+            Ok("Day07, 7".into())
+        );
+        assert_eq!(
+            render("no placeholders", &values),
+            Ok("no placeholders".into())
+        );
+        assert_eq!(
+            render("{{year}}", &values),
+            Err("unknown placeholder `{{year}}`".into())
+        );
+        assert!(render("{{day", &values).is_err());
+    }
+}
@@ -0,0 +1,52 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::time::{Duration, Instant};
+
+/// A value together with the time it took to compute it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timed<T> {
+    pub value: T,
+    pub elapsed: Duration,
+}
+
+impl<T> Timed<T> {
+    /// Runs `func` and measures its raw execution time.
+    pub fn measure(func: impl FnOnce() -> T) -> Self {
+        let timer = Instant::now();
+        let value = func();
+        let elapsed = timer.elapsed();
+        Timed { value, elapsed }
+    }
+}
+
+/// Converts `duration` to fractional milliseconds without losing sub-microsecond precision.
+pub fn as_millis_f64(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000_f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let timed = Timed::measure(|| 6 * 7);
+        assert_eq!(timed.value, 42);
+        assert!(timed.elapsed < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_as_millis_f64() {
+        let cases = [
+            (Duration::from_nanos(74), 0.000074),
+            (Duration::from_micros(1450), 1.45),
+            (Duration::from_millis(10_300), 10300_f64),
+        ];
+
+        for (duration, expected) in cases {
+            assert!((as_millis_f64(duration) - expected).abs() < 1.0e-9);
+        }
+    }
+}
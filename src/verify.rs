@@ -0,0 +1,250 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    fmt::{self, Display},
+    fs,
+    io::{self, ErrorKind},
+    path::PathBuf,
+};
+
+use crate::{manifest, read_optional, report::json_string, year_path, Answer, SolveOutcome};
+
+/// Accepted answers for a day, stored in `src/answers/<year>/NN.toml`.
+///
+/// The file uses a small subset of TOML:
+/// ```toml
+/// part_one = 24000
+/// part_two = "CMZ"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RecordedAnswers {
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+}
+
+impl RecordedAnswers {
+    pub fn get(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part_one.as_ref(),
+            2 => self.part_two.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: Answer) {
+        match part {
+            1 => self.part_one = Some(answer),
+            2 => self.part_two = Some(answer),
+            _ => panic!("unknown part {}, expected 1 or 2", part),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let mut answers = RecordedAnswers::default();
+
+        for section in manifest::parse(contents)? {
+            if let Some(name) = section.name {
+                return Err(format!("unexpected section `[{}]`", name));
+            }
+            for entry in section.entries {
+                answers.set(entry.part, parse_entry(&entry)?);
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+/// Parses the value of a `part_one = ...` or `part_two = ...` key into an answer.
+pub(crate) fn parse_entry(entry: &manifest::Entry) -> Result<Answer, String> {
+    parse_value(entry.value).map_err(|e| format!("line {}: {}", entry.line, e))
+}
+
+impl Display for RecordedAnswers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (key, answer) in [("part_one", &self.part_one), ("part_two", &self.part_two)] {
+            match answer {
+                Some(Answer::Integer(value)) => writeln!(f, "{} = {}", key, value)?,
+                Some(Answer::SignedInteger(value)) => writeln!(f, "{} = {}", key, value)?,
+                // TOML basic strings use the same escapes as JSON strings.
+                Some(answer) => writeln!(f, "{} = {}", key, json_string(&answer.to_string()))?,
+                None => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_value(value: &str) -> Result<Answer, String> {
+    if let Some(quoted) = value.strip_prefix('"') {
+        let text = unescape(quoted.strip_suffix('"').ok_or("unterminated string")?)?;
+        return Ok(if text.contains('\n') {
+            Answer::AsciiArt(text)
+        } else {
+            Answer::Text(text)
+        });
+    }
+
+    if value.starts_with('-') {
+        value.parse::<i64>().map(Answer::SignedInteger)
+    } else {
+        value.parse::<u64>().map(Answer::Integer)
+    }
+    .map_err(|_| format!("expected an integer or a quoted string, got `{}`", value))
+}
+
+fn unescape(value: &str) -> Result<String, String> {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            result.push(ch);
+            continue;
+        }
+
+        match chars.next() {
+            Some('"') => result.push('"'),
+            Some('\\') => result.push('\\'),
+            Some('n') => result.push('\n'),
+            Some('r') => result.push('\r'),
+            Some('t') => result.push('\t'),
+            Some('u') => {
+                let code: String = chars.by_ref().take(4).collect();
+                let ch = u32::from_str_radix(&code, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| format!("invalid unicode escape `\\u{}`", code))?;
+                result.push(ch);
+            }
+            other => return Err(format!("invalid escape `\\{}`", other.unwrap_or(' '))),
+        }
+    }
+
+    Ok(result)
+}
+
+fn answers_path(year: i16, day: u8) -> PathBuf {
+    year_path("answers", year).join(format!("{:02}.toml", day))
+}
+
+/// Loads the recorded answers for `day` of `year`. A missing file means nothing was recorded yet.
+pub fn load(year: i16, day: u8) -> io::Result<RecordedAnswers> {
+    let path = answers_path(year, day);
+
+    match read_optional(&path)? {
+        Some(contents) => RecordedAnswers::parse(&contents).map_err(|e| {
+            io::Error::new(
+                ErrorKind::InvalidData,
+                format!("could not parse \"{}\": {}", path.display(), e),
+            )
+        }),
+        None => Ok(RecordedAnswers::default()),
+    }
+}
+
+/// Writes `answers` to the answers file of `day` of `year` and returns its path.
+pub fn save(year: i16, day: u8, answers: &RecordedAnswers) -> io::Result<PathBuf> {
+    let path = answers_path(year, day);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&path, answers.to_string())?;
+    Ok(path)
+}
+
+/// Result of checking an outcome against a recorded answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong {
+        expected: Answer,
+    },
+    /// No answer was recorded for this part.
+    Unknown,
+}
+
+impl Verdict {
+    /// Checks `outcome` against `expected`.
+    ///
+    /// Answers are compared by their printed form, like the puzzle page does,
+    /// so that e.g. a signed and an unsigned integer with the same value match.
+    pub fn check(outcome: &SolveOutcome, expected: Option<&Answer>) -> Self {
+        match (outcome.answer(), expected) {
+            (_, None) => Verdict::Unknown,
+            (Some(answer), Some(expected)) if answer.to_string() == expected.to_string() => {
+                Verdict::Correct
+            }
+            (_, Some(expected)) => Verdict::Wrong {
+                expected: expected.clone(),
+            },
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verdict = match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong { .. } => "wrong",
+            Verdict::Unknown => "unknown",
+        };
+        write!(f, "{}", verdict)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        let contents = "# day 10\npart_one = 13140\npart_two = \"#.\\n.#\"\n";
+        let answers = RecordedAnswers::parse(contents).unwrap();
+
+        assert_eq!(answers.part_one, Some(Answer::Integer(13140)));
+        assert_eq!(answers.part_two, Some(Answer::ascii_art(["#.", ".#"])));
+        assert_eq!(
+            answers.to_string(),
+            "part_one = 13140\npart_two = \"#.\\n.#\"\n"
+        );
+
+        let answers = RecordedAnswers::parse("part_one = -3\npart_two = \"CMZ\"").unwrap();
+        assert_eq!(answers.part_one, Some(Answer::SignedInteger(-3)));
+        assert_eq!(answers.part_two, Some(Answer::Text("CMZ".into())));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(RecordedAnswers::parse("part_three = 1").is_err());
+        assert!(RecordedAnswers::parse("part_one = \"CMZ").is_err());
+        assert!(RecordedAnswers::parse("part_one = CMZ").is_err());
+        assert!(RecordedAnswers::parse("part_one").is_err());
+        assert!(RecordedAnswers::parse("[top_bottom]\npart_one = 1").is_err());
+    }
+
+    #[test]
+    fn test_verdict() {
+        let solved = SolveOutcome::Solved(Answer::SignedInteger(13140));
+
+        assert_eq!(Verdict::check(&solved, None), Verdict::Unknown);
+        assert_eq!(
+            Verdict::check(&solved, Some(&Answer::Integer(13140))),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::check(&solved, Some(&Answer::Integer(1))),
+            Verdict::Wrong {
+                expected: Answer::Integer(1)
+            }
+        );
+        assert_eq!(
+            Verdict::check(&SolveOutcome::NotImplemented, Some(&Answer::Integer(1))),
+            Verdict::Wrong {
+                expected: Answer::Integer(1)
+            }
+        );
+    }
+}
@@ -0,0 +1,362 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs,
+    io::{self, Write},
+    path::PathBuf,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    examples, input,
+    runner::solve_part_within,
+    src_path,
+    verify::{self, Verdict},
+    year_path, Solution, SolveOutcome, ANSI_ITALIC, ANSI_RESET,
+};
+
+/// Shortest time between two checks for changes, so polling does not keep a core busy.
+pub const MIN_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Parses the time between two checks for changes in (fractional) seconds, e.g. `0.5`.
+pub fn parse_interval(s: &str) -> Result<Duration, String> {
+    match s.parse::<f64>().map(Duration::try_from_secs_f64) {
+        Ok(Ok(interval)) if interval >= MIN_INTERVAL => Ok(interval),
+        _ => Err(format!(
+            "invalid interval \"{}\", expected at least {} seconds",
+            s,
+            MIN_INTERVAL.as_secs_f64()
+        )),
+    }
+}
+
+/// Returns the files that affect the answers of `day` of `year`: its module, the shared helpers,
+/// its examples with their expected answers and its input.
+///
+/// Examples and the modules in `src/helpers/` are listed when this is called, so it picks up files that were added in the meantime.
+pub fn watched_paths(year: i16, day: u8) -> Vec<PathBuf> {
+    let mut paths = vec![
+        src_path("days")
+            .join(format!("y{}", year))
+            .join(format!("day{:02}.rs", day)),
+        src_path("helpers.rs"),
+        year_path("examples", year).join(format!("{:02}.toml", day)),
+        year_path("inputs", year).join(format!("{:02}.txt", day)),
+    ];
+
+    if let Ok(entries) = fs::read_dir(src_path("helpers")) {
+        let mut helpers: Vec<PathBuf> = entries.flatten().map(|entry| entry.path()).collect();
+        helpers.sort();
+        paths.extend(helpers);
+    }
+
+    if let Ok(examples) = examples::discover(year, day) {
+        paths.extend(examples.iter().map(|example| example.path()));
+    }
+
+    paths
+}
+
+/// Modification times of a set of files, `None` for files that do not exist.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Snapshot(pub BTreeMap<PathBuf, Option<SystemTime>>);
+
+impl Snapshot {
+    pub fn take(paths: &[PathBuf]) -> Self {
+        Snapshot(
+            paths
+                .iter()
+                .map(|path| {
+                    let modified = fs::metadata(path).and_then(|m| m.modified()).ok();
+                    (path.clone(), modified)
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the files that were created, modified or removed since `previous`.
+    pub fn changed_since(&self, previous: &Snapshot) -> Vec<PathBuf> {
+        let mut changed: Vec<PathBuf> = self
+            .0
+            .iter()
+            .filter(|(path, modified)| previous.0.get(*path) != Some(modified))
+            .map(|(path, _)| path.clone())
+            .collect();
+        changed.extend(
+            previous
+                .0
+                .iter()
+                .filter(|(path, modified)| modified.is_some() && !self.0.contains_key(*path))
+                .map(|(path, _)| path.clone()),
+        );
+        changed
+    }
+}
+
+/// The outcomes of a run, keyed by what was run, e.g. `08_top_bottom part 1` or `input part 2`.
+///
+/// Stored as one `<key>\t<outcome>` line per part, with line breaks in outcomes escaped as `\n`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RunAnswers(pub BTreeMap<String, String>);
+
+impl RunAnswers {
+    pub fn insert(&mut self, key: String, outcome: &SolveOutcome) {
+        let value = outcome
+            .to_string()
+            .replace('\\', "\\\\")
+            .replace('\n', "\\n");
+        self.0.insert(key, value);
+    }
+
+    /// Parses the stored form, skipping malformed lines.
+    pub fn parse(contents: &str) -> Self {
+        RunAnswers(
+            contents
+                .lines()
+                .filter_map(|line| line.split_once('\t'))
+                .map(|(key, value)| (key.to_owned(), value.to_owned()))
+                .collect(),
+        )
+    }
+}
+
+impl Display for RunAnswers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (key, value) in &self.0 {
+            writeln!(f, "{}\t{}", key, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// An answer that differs between two runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    Added {
+        key: String,
+        value: String,
+    },
+    Changed {
+        key: String,
+        from: String,
+        to: String,
+    },
+    Removed {
+        key: String,
+        value: String,
+    },
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Added { key, value } => write!(f, "+ {}: {}", key, value),
+            Change::Changed { key, from, to } => write!(f, "~ {}: {} -> {}", key, from, to),
+            Change::Removed { key, value } => write!(f, "- {}: {}", key, value),
+        }
+    }
+}
+
+/// Lists the answers that were added, changed or removed in `current` compared to `previous`.
+pub fn diff(previous: &RunAnswers, current: &RunAnswers) -> Vec<Change> {
+    let mut changes = Vec::new();
+
+    for (key, value) in &current.0 {
+        match previous.0.get(key) {
+            None => changes.push(Change::Added {
+                key: key.clone(),
+                value: value.clone(),
+            }),
+            Some(from) if from != value => changes.push(Change::Changed {
+                key: key.clone(),
+                from: from.clone(),
+                to: value.clone(),
+            }),
+            Some(_) => {}
+        }
+    }
+
+    for (key, value) in &previous.0 {
+        if !current.0.contains_key(key) {
+            changes.push(Change::Removed {
+                key: key.clone(),
+                value: value.clone(),
+            });
+        }
+    }
+
+    changes
+}
+
+fn write_line(
+    out: &mut impl Write,
+    key: &str,
+    outcome: &SolveOutcome,
+    verdict: &Verdict,
+    elapsed: Duration,
+) -> io::Result<()> {
+    let mark = match verdict {
+        Verdict::Correct => " ✔".to_owned(),
+        Verdict::Wrong { expected } => format!(" ✘ expected {}", expected),
+        Verdict::Unknown => String::new(),
+    };
+    let separator = if outcome.answer().is_some_and(|a| a.is_multiline()) {
+        "\n"
+    } else {
+        " "
+    };
+
+    writeln!(
+        out,
+        "{}:{}{}{} {}({:.2?}){}",
+        key, separator, outcome, mark, ANSI_ITALIC, elapsed, ANSI_RESET
+    )
+}
+
+/// Runs `parts` of `solution` against every example and checks them against the expected answers,
+/// then against the real input if no example failed.
+///
+/// Writes a line per run part to `out` and returns the outcomes and whether all examples passed.
+/// Examples that fail, panic or take longer than `timeout` count as not passed.
+pub fn check(
+    solution: &'static dyn Solution,
+    parts: &[u8],
+    timeout: Option<Duration>,
+    out: &mut impl Write,
+) -> io::Result<(RunAnswers, bool)> {
+    let (year, day) = (solution.year(), solution.day());
+    let expected = examples::load_expected(year, day)?;
+    let mut answers = RunAnswers::default();
+    let mut passed = true;
+
+    for example in examples::discover(year, day)? {
+        let input = example.read()?;
+        for &part in parts {
+            let result = solve_part_within(solution, part, &input, timeout);
+            let verdict = Verdict::check(
+                &result.value,
+                expected.get(&example).and_then(|answers| answers.get(part)),
+            );
+            passed &= !matches!(verdict, Verdict::Wrong { .. }) && result.value.error().is_none();
+
+            let key = format!("{} part {}", example, part);
+            write_line(out, &key, &result.value, &verdict, result.elapsed)?;
+            answers.insert(key, &result.value);
+        }
+    }
+
+    if !passed {
+        writeln!(out, "Examples failed, skipping the input.")?;
+        return Ok((answers, false));
+    }
+
+    let input = match input::read_input(year, day, None) {
+        Ok(input) => input,
+        Err(e) => {
+            writeln!(out, "{}", e)?;
+            return Ok((answers, true));
+        }
+    };
+    let recorded = verify::load(year, day)?;
+
+    for &part in parts {
+        let result = solve_part_within(solution, part, &input, timeout);
+        let verdict = Verdict::check(&result.value, recorded.get(part));
+
+        let key = format!("input part {}", part);
+        write_line(out, &key, &result.value, &verdict, result.elapsed)?;
+        answers.insert(key, &result.value);
+    }
+
+    Ok((answers, true))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{days, temp::TempPath, Answer};
+
+    #[test]
+    fn test_parse_interval() {
+        assert_eq!(parse_interval("0.5"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_interval("0.05"), Ok(MIN_INTERVAL));
+        for invalid in ["0", "0.01", "-1", "NaN", "inf", "often"] {
+            assert!(parse_interval(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let mut previous = RunAnswers::default();
+        previous.insert(
+            "08 part 1".into(),
+            &SolveOutcome::Solved(Answer::Integer(21)),
+        );
+        previous.insert("08 part 2".into(), &SolveOutcome::NotImplemented);
+        previous.insert(
+            "input part 1".into(),
+            &SolveOutcome::Solved(Answer::Integer(1)),
+        );
+
+        let mut current = RunAnswers::default();
+        current.insert(
+            "08 part 1".into(),
+            &SolveOutcome::Solved(Answer::Integer(21)),
+        );
+        current.insert(
+            "08 part 2".into(),
+            &SolveOutcome::Solved(Answer::Integer(8)),
+        );
+        current.insert(
+            "10 part 2".into(),
+            &SolveOutcome::Solved(Answer::ascii_art(["#.", ".#"])),
+        );
+
+        let changes: Vec<String> = diff(&previous, &current)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                "~ 08 part 2: not solved. -> 8",
+                "+ 10 part 2: #.\\n.#",
+                "- input part 1: 1",
+            ]
+        );
+
+        assert_eq!(RunAnswers::parse(&current.to_string()), current);
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let path = TempPath::new("watch.txt");
+        let paths = [path.to_path_buf()];
+
+        let missing = Snapshot::take(&paths);
+        fs::write(&path, "1").unwrap();
+        let created = Snapshot::take(&paths);
+
+        assert_eq!(created.changed_since(&missing), paths);
+        assert!(created.changed_since(&created).is_empty());
+    }
+
+    #[test]
+    fn test_check() {
+        let solution = days::get(2022, 8).unwrap();
+        let mut out = Vec::new();
+        let (answers, passed) = check(solution, &[1], None, &mut out).unwrap();
+
+        assert!(passed);
+        assert_eq!(answers.0.get("08 part 1").map(String::as_str), Some("21"));
+        assert_eq!(
+            answers.0.get("08_top_bottom part 1").map(String::as_str),
+            Some("23")
+        );
+        assert!(String::from_utf8(out).unwrap().contains("08 part 1: 21 ✔ "));
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    advent_of_code::run_bin(&advent_of_code::days::y{{year}}::day{{day_padded}}::Day{{day_padded}});
+}
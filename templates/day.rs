@@ -0,0 +1,11 @@
+//! [Day {{day}}: {{title}}](https://adventofcode.com/{{year}}/day/{{day}})
+
+pub fn part_one(_input: &str) -> Option<{{answer}}> {
+    None
+}
+
+pub fn part_two(_input: &str) -> Option<{{answer}}> {
+    None
+}
+
+crate::solution!(Day{{day_padded}}, {{year}}, {{day}}, {{title_string}});
@@ -0,0 +1,18 @@
+//! [Day {{day}}: {{title}}](https://adventofcode.com/{{year}}/day/{{day}})
+
+/// Parses the puzzle input into the representation both parts work on.
+fn parse(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+pub fn part_one(input: &str) -> Option<{{answer}}> {
+    let _parsed = parse(input);
+    None
+}
+
+pub fn part_two(input: &str) -> Option<{{answer}}> {
+    let _parsed = parse(input);
+    None
+}
+
+crate::solution!(Day{{day_padded}}, {{year}}, {{day}}, {{title_string}});
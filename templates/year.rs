@@ -0,0 +1,12 @@
+/*
+ * Registry of the solutions of {{year}}.
+ * `cargo scaffold` adds a `pub mod dayNN;` declaration and an entry to `ALL` for every new solution.
+ */
+use crate::Solution;
+
+pub mod day{{day_padded}};
+
+/// All implemented solutions of the year, ordered by day.
+pub static ALL: &[&dyn Solution] = &[
+    &day{{day_padded}}::Day{{day_padded}},
+];